@@ -0,0 +1,204 @@
+use std::fmt::{self, Display, Formatter};
+
+///
+/// # The accepted difference between two floats
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance {
+    /// The maximum absolute difference
+    Absolute(f64),
+    /// The maximum difference relative to the largest magnitude
+    Relative(f64),
+    /// The maximum number of representable floats between the values
+    Ulps(u64),
+}
+
+impl Display for Tolerance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Absolute(epsilon) => write!(f, "absolute {epsilon:e}"),
+            Self::Relative(tolerance) => write!(f, "relative {tolerance:e}"),
+            Self::Ulps(ulps) => write!(f, "{ulps} ulps"),
+        }
+    }
+}
+
+///
+/// # Compare values with a tolerance
+///
+pub trait Approx {
+    ///
+    /// # Check if the value is approximately equal to the expected value
+    ///
+    /// - `expected` The expected value
+    /// - `tolerance` The accepted difference
+    ///
+    /// # Errors
+    ///
+    /// With the actual difference and the tolerance
+    ///
+    fn approx(&self, expected: &Self, tolerance: Tolerance) -> Result<(), String>;
+}
+
+impl Approx for f64 {
+    fn approx(&self, expected: &Self, tolerance: Tolerance) -> Result<(), String> {
+        if self.eq(expected) {
+            return Ok(());
+        }
+        let difference: f64 = (self - expected).abs();
+        let accepted: bool = match tolerance {
+            Tolerance::Absolute(epsilon) => difference.le(&epsilon),
+            Tolerance::Relative(relative) => {
+                difference.is_finite()
+                    && difference.le(&(relative * self.abs().max(expected.abs())))
+            }
+            Tolerance::Ulps(ulps) => {
+                return match ulps_f64(*self, *expected) {
+                    Some(distance) if distance.le(&ulps) => Ok(()),
+                    Some(distance) => Err(format!(
+                        "{self} and {expected} are {distance} ulps apart, the tolerance is {tolerance}"
+                    )),
+                    None => Err(format!("{self} and {expected} can't be compared")),
+                };
+            }
+        };
+        if accepted {
+            Ok(())
+        } else {
+            Err(format!(
+                "{self} and {expected} differ by {difference:e}, the tolerance is {tolerance}"
+            ))
+        }
+    }
+}
+
+impl Approx for f32 {
+    fn approx(&self, expected: &Self, tolerance: Tolerance) -> Result<(), String> {
+        match tolerance {
+            Tolerance::Ulps(ulps) => match ulps_f32(*self, *expected) {
+                Some(distance) if u64::from(distance).le(&ulps) => Ok(()),
+                Some(distance) => Err(format!(
+                    "{self} and {expected} are {distance} ulps apart, the tolerance is {tolerance}"
+                )),
+                None => Err(format!("{self} and {expected} can't be compared")),
+            },
+            tolerance => f64::from(*self).approx(&f64::from(*expected), tolerance),
+        }
+    }
+}
+
+impl<T: Approx> Approx for [T] {
+    fn approx(&self, expected: &Self, tolerance: Tolerance) -> Result<(), String> {
+        if self.len().ne(&expected.len()) {
+            return Err(format!(
+                "the length {} differs of the expected length {}",
+                self.len(),
+                expected.len()
+            ));
+        }
+        self.iter()
+            .zip(expected)
+            .enumerate()
+            .try_for_each(|(i, (a, e))| a.approx(e, tolerance).map_err(|m| format!("at {i}: {m}")))
+    }
+}
+
+impl<T: Approx, const N: usize> Approx for [T; N] {
+    fn approx(&self, expected: &Self, tolerance: Tolerance) -> Result<(), String> {
+        self.as_slice().approx(expected.as_slice(), tolerance)
+    }
+}
+
+impl<T: Approx> Approx for Vec<T> {
+    fn approx(&self, expected: &Self, tolerance: Tolerance) -> Result<(), String> {
+        self.as_slice().approx(expected.as_slice(), tolerance)
+    }
+}
+
+///
+/// # Count the representable f64 between two values
+///
+/// - `a` The first value
+/// - `b` The second value
+///
+fn ulps_f64(a: f64, b: f64) -> Option<u64> {
+    if a.is_nan() || b.is_nan() {
+        return None;
+    }
+    let order = |x: f64| -> i128 {
+        let bits: i64 = x.to_bits() as i64;
+        if bits < 0 {
+            i128::from(i64::MIN) - i128::from(bits)
+        } else {
+            i128::from(bits)
+        }
+    };
+    u64::try_from((order(a) - order(b)).unsigned_abs()).ok()
+}
+
+///
+/// # Count the representable f32 between two values
+///
+/// - `a` The first value
+/// - `b` The second value
+///
+fn ulps_f32(a: f32, b: f32) -> Option<u32> {
+    if a.is_nan() || b.is_nan() {
+        return None;
+    }
+    let order = |x: f32| -> i64 {
+        let bits: i32 = x.to_bits() as i32;
+        if bits < 0 {
+            i64::from(i32::MIN) - i64::from(bits)
+        } else {
+            i64::from(bits)
+        }
+    };
+    u32::try_from((order(a) - order(b)).unsigned_abs()).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::approx::{Approx, Tolerance};
+
+    #[test]
+    fn modes() {
+        assert!((0.1_f64 + 0.2)
+            .approx(&0.3, Tolerance::Absolute(1e-12))
+            .is_ok());
+        assert!((0.1_f64 + 0.2).approx(&0.3, Tolerance::Ulps(1)).is_ok());
+        assert!(0.0_f64.approx(&-0.0, Tolerance::Ulps(0)).is_ok());
+        assert!(1000.0_f64
+            .approx(&1001.0, Tolerance::Relative(1e-2))
+            .is_ok());
+        assert!(f64::NAN.approx(&f64::NAN, Tolerance::Ulps(4)).is_err());
+        assert!(f64::INFINITY
+            .approx(&f64::INFINITY, Tolerance::Absolute(0.1))
+            .is_ok());
+        assert!(f32::NEG_INFINITY
+            .approx(&f32::NEG_INFINITY, Tolerance::Relative(1e-6))
+            .is_ok());
+        assert!(f64::INFINITY
+            .approx(&f64::NEG_INFINITY, Tolerance::Relative(1e-6))
+            .is_err());
+        assert!(1.0_f64
+            .approx(&f64::INFINITY, Tolerance::Relative(1e-6))
+            .is_err());
+        assert!(1.0_f32.approx(&1.000_000_1, Tolerance::Ulps(1)).is_ok());
+        assert_eq!(
+            1.0_f64.approx(&1.5, Tolerance::Absolute(0.1)),
+            Err(String::from(
+                "1 and 1.5 differ by 5e-1, the tolerance is absolute 1e-1"
+            ))
+        );
+        assert_eq!(
+            [1.0_f32, 2.0].approx(&[1.0, 2.5], Tolerance::Absolute(0.1)),
+            Err(String::from(
+                "at 1: 2 and 2.5 differ by 5e-1, the tolerance is absolute 1e-1"
+            ))
+        );
+        assert!(vec![1.0_f64]
+            .approx(&vec![1.0, 2.0], Tolerance::Ulps(0))
+            .is_err());
+    }
+}
@@ -1,98 +1,400 @@
-use colored_truecolor::Colorize;
 use is_executable::IsExecutable;
-use progress_bar::{
-    finalize_progress_bar, inc_progress_bar, init_progress_bar, print_progress_bar_final_info,
-    print_progress_bar_info, set_progress_bar_action, Color, Style,
-};
 use regex::Regex;
 use std::cell::Cell;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::future::Future;
+use std::panic::Location;
 use std::path::Path;
 use std::process::ExitStatus;
-use std::thread::sleep;
 use std::time::{Duration, Instant};
 use std::{fs, io};
 
+use crate::approx::{Approx, Tolerance};
+use crate::command::Command;
+use crate::executor;
+use crate::filter::Filter;
+use crate::junit::JunitReporter;
+use crate::matcher::{self, Matcher};
 use crate::objects::{Failure, Success, Take, Testable, Theory};
 use crate::output::{
-    ASSERT_BEGIN, ASSERT_BETWEEN, ASSERT_CONTAINS, ASSERT_EQUALS, ASSERT_EXISTS, ASSERT_FAIL,
+    ASSERT_APPROX_EQUALS, ASSERT_BEGIN, ASSERT_CONTAINS, ASSERT_EQUALS, ASSERT_EXISTS, ASSERT_FAIL,
     ASSERT_FINNISH, ASSERT_IS_EXECUTABLE, ASSERT_KO, ASSERT_MATCH, ASSERT_NOT_CONTAINS,
-    ASSERT_NOT_EXISTS, ASSERT_OK, ASSERT_SHOULD_BE_BEGIN, ASSERT_SHOULD_BE_BETWEEN,
-    ASSERT_SHOULD_BE_EQUALS, ASSERT_SHOULD_BE_EXECUTABLE, ASSERT_SHOULD_BE_EXISTS,
-    ASSERT_SHOULD_BE_FAIL, ASSERT_SHOULD_BE_FINNISH, ASSERT_SHOULD_BE_KO,
+    ASSERT_NOT_EXISTS, ASSERT_OK, ASSERT_PANIC, ASSERT_SHOULD_BE_APPROX_EQUALS,
+    ASSERT_SHOULD_BE_BEGIN, ASSERT_SHOULD_BE_EQUALS, ASSERT_SHOULD_BE_EXECUTABLE,
+    ASSERT_SHOULD_BE_EXISTS, ASSERT_SHOULD_BE_FAIL, ASSERT_SHOULD_BE_FINNISH, ASSERT_SHOULD_BE_KO,
     ASSERT_SHOULD_BE_NOT_CONTAINS, ASSERT_SHOULD_BE_NOT_EXISTS, ASSERT_SHOULD_BE_OK,
     ASSERT_SHOULD_BE_SUCCESS, ASSERT_SHOULD_BE_SUPERIOR, ASSERT_SHOULD_BE_UNEQUALS,
     ASSERT_SHOULD_CONTAINS, ASSERT_SHOULD_MATCH, ASSERT_SUCCESS, ASSERT_SUPERIOR,
     ASSERT_THEORY_IS_FALSE, ASSERT_THEORY_IS_TRUE, ASSERT_THEORY_SHOULD_BE_FALSE,
-    ASSERT_THEORY_SHOULD_BE_TRUE, ASSERT_UNEQUALS, IS_FAIL, IS_SUCCESS, THEORY_IS_FALSE,
-    THEORY_IS_TRUE,
+    ASSERT_THEORY_SHOULD_BE_TRUE, ASSERT_UNEQUALS, IS_FAIL, IS_REPORT_NOT_WRITTEN,
+    IS_REPORT_WRITTEN, IS_SNAPSHOT_CHANGED, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_NEW, IS_SPAWN_FAILED,
+    IS_SUCCESS, THEORY_IS_FALSE, THEORY_IS_TRUE,
 };
+use crate::property::{self, Config, Generator};
+use crate::report::{Outcome, Record, Report};
+use crate::reporter::{self, Reporter};
+use crate::snapshot::{self, Snapshot};
 
 ///
 /// # To run assertions tests
 ///
 pub struct Assert {
     c: Cell<usize>,
-    sleep: u64,
-    messages: HashMap<usize, String>,
-    take: HashMap<usize, u128>,
+    report: Report,
+    reporters: Vec<Box<dyn Reporter>>,
+    values: Option<(String, String)>,
+    clock: Option<Instant>,
+    timeout: Option<Duration>,
+}
+
+impl Assert {
+    ///
+    /// # Write the junit report in a directory at the end
+    ///
+    /// - `directory` The junit output directory
+    ///
+    pub fn junit(&mut self, directory: &str) -> &mut Self {
+        self.reporter(JunitReporter::new(Some(directory)))
+    }
+
+    ///
+    /// # Add a reporter
+    ///
+    /// A reporter added in a running test is started with it.
+    ///
+    /// - `reporter` The reporter receiving the events of the run
+    ///
+    pub fn reporter(&mut self, mut reporter: impl Reporter + 'static) -> &mut Self {
+        if !self.report.title.is_empty() {
+            reporter.suite_started(&self.report.title, &self.report.description);
+        }
+        self.reporters.push(Box::new(reporter));
+        self
+    }
+
+    ///
+    /// # Add a reporter before the run
+    ///
+    /// - `reporter` The reporter receiving the events of the run
+    ///
+    #[must_use]
+    pub fn with_reporter(mut self, reporter: Box<dyn Reporter>) -> Self {
+        self.reporters.push(reporter);
+        self
+    }
+
+    ///
+    /// # Run the callbacks with the reporters of the assert and return the report
+    ///
+    /// - `title` The title
+    /// - `description` The description
+    /// - `callbacks` The callbacks to execute
+    ///
+    /// # Panics
+    ///
+    /// On failure
+    ///
+    pub fn run_it(
+        mut self,
+        title: &str,
+        description: &str,
+        callbacks: Vec<&dyn Fn(&mut Self) -> &mut Self>,
+    ) -> Report {
+        self.report = Report::new(title, description);
+        for reporter in &mut self.reporters {
+            reporter.suite_started(title, description);
+        }
+
+        let mut j = &mut self;
+        if Filter::from_env().selects(title) {
+            for &c in &callbacks {
+                j = c(j);
+            }
+        } else {
+            j = j.skip("it", title);
+        }
+        assert!(j.end());
+        j.report()
+    }
+
+    ///
+    /// # Fail the async assertions not completed before a timeout
+    ///
+    /// - `timeout` The maximum duration of a future
+    ///
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    ///
+    /// # Attach the expected and the actual values to the next assertion
+    ///
+    /// - `expected` The expected value
+    /// - `actual` The actual value
+    ///
+    fn values(&mut self, expected: &str, actual: &str) -> &mut Self {
+        self.values = Some((expected.to_string(), actual.to_string()));
+        self
+    }
+
+    ///
+    /// # Run a callback, the next record is timed from its start
+    ///
+    /// - `callback` The tested callback
+    ///
+    fn timed<T>(&mut self, callback: impl FnOnce() -> T) -> T {
+        self.clock = Some(Instant::now());
+        callback()
+    }
+
+    ///
+    /// # Run an assertion, measure the time and record it
+    ///
+    /// - `kind` The assertion kind
+    /// - `t` The test
+    /// - `s` The success output message
+    /// - `e` The error output message
+    ///
+    #[track_caller]
+    fn take_as(&mut self, kind: &str, t: bool, s: &str, e: &str) -> &mut Self {
+        self.check_as(kind, t, s, e);
+        self
+    }
+
+    ///
+    /// # Run an assertion without chaining, measure the time and record it
+    ///
+    /// - `kind` The assertion kind
+    /// - `t` The test
+    /// - `s` The success output message
+    /// - `e` The error output message
+    ///
+    #[track_caller]
+    fn check_as(&mut self, kind: &str, t: bool, s: &str, e: &str) {
+        let i: Instant = self.clock.take().unwrap_or_else(Instant::now);
+
+        if t.eq(&false) {
+            self.record(kind, e, Outcome::Failed, i.elapsed().as_nanos());
+            self.fail_with(s, e);
+        }
+        if self.assert_that(t) {
+            self.record(kind, s, Outcome::Passed, i.elapsed().as_nanos());
+        }
+    }
+
+    ///
+    /// # Panic with the failure message and the diff of the last recorded values
+    ///
+    /// - `s` The success output message
+    /// - `e` The error output message
+    ///
+    #[track_caller]
+    fn fail_with(&self, s: &str, e: &str) -> ! {
+        match self.report.records.last().and_then(Record::diff) {
+            Some(diff) => panic!("{}", format_args!("{s} match {e}\n{diff}")),
+            None => panic!("{}", format_args!("{s} match {e}")),
+        }
+    }
+
+    ///
+    /// # Run a future, compare its output and record it
+    ///
+    /// - `kind` The assertion kind
+    /// - `future` The future to run
+    /// - `expected` The expected output
+    /// - `s` The success output message
+    /// - `e` The error output message
+    ///
+    #[track_caller]
+    fn take_async<T: PartialEq, F: Future<Output = T>>(
+        &mut self,
+        kind: &str,
+        future: F,
+        expected: &T,
+        s: &str,
+        e: &str,
+    ) -> &mut Self {
+        let timeout: Option<Duration> = self.timeout;
+        match self.timed(|| executor::block_on_timeout(future, timeout)) {
+            Some(actual) => self.take_as(kind, actual.eq(expected), s, e),
+            None => {
+                let e: String = executor::timed_out(self.timeout.unwrap_or_default());
+                self.take_as(kind, false, s, &e)
+            }
+        }
+    }
+
+    ///
+    /// # Append a skipped assertion to the report
+    ///
+    /// - `kind` The assertion kind
+    /// - `message` The output message
+    ///
+    #[track_caller]
+    fn skip(&mut self, kind: &str, message: &str) -> &mut Self {
+        let mut record = Record::new(kind, message, Outcome::Skipped, 0, &self.report.title);
+        record.location = Some(Location::caller());
+        for reporter in &mut self.reporters {
+            reporter.assertion(&record, 0);
+        }
+        self.report.records.push(record);
+        self
+    }
+
+    ///
+    /// # Append an assertion to the report
+    ///
+    /// - `kind` The assertion kind
+    /// - `message` The output message
+    /// - `outcome` The assertion outcome
+    /// - `take` The elapsed time in nanoseconds
+    ///
+    #[track_caller]
+    fn record(&mut self, kind: &str, message: &str, outcome: Outcome, take: u128) {
+        let mut record = Record::new(kind, message, outcome, take, &self.report.title);
+        if let Some((expected, actual)) = self.values.take() {
+            record = record.with_values(&expected, &actual);
+        }
+        record.location = Some(Location::caller());
+        for reporter in &mut self.reporters {
+            reporter.assertion(&record, 0);
+        }
+        self.report.records.push(record);
+    }
 }
 
 impl Success for Assert {
+    #[track_caller]
     fn run(&mut self, callbacks: Vec<&dyn Fn() -> Result<ExitStatus, io::Error>>) -> &mut Self {
         for &c in &callbacks {
-            self.check(
-                c().unwrap().success(),
-                ASSERT_SUCCESS,
-                ASSERT_SHOULD_BE_SUCCESS,
-            );
+            match self.timed(c) {
+                Ok(status) => self.check_as(
+                    "run",
+                    status.success(),
+                    ASSERT_SUCCESS,
+                    ASSERT_SHOULD_BE_SUCCESS,
+                ),
+                Err(e) => self.check_as(
+                    "run",
+                    false,
+                    ASSERT_SUCCESS,
+                    &format!("{IS_SPAWN_FAILED}: {e}"),
+                ),
+            }
         }
         self
     }
 
+    #[track_caller]
     fn success(&mut self, callbacks: Vec<&dyn Fn() -> bool>) -> &mut Self {
         for &c in &callbacks {
-            self.check(c(), IS_SUCCESS, IS_FAIL);
+            let t: bool = self.timed(c);
+            self.check_as("success", t, IS_SUCCESS, IS_FAIL);
         }
         self
     }
 }
 
 impl Theory for Assert {
+    #[track_caller]
     fn chaos(&mut self, callback: &dyn Fn() -> bool) -> &mut Self {
-        self.take(
-            !callback(),
+        let t: bool = !self.timed(callback);
+        self.take_as(
+            "chaos",
+            t,
             ASSERT_THEORY_IS_FALSE,
             ASSERT_THEORY_SHOULD_BE_FALSE,
         )
     }
+    #[track_caller]
     fn theorem<T: PartialEq>(&mut self, expected: T, actual: &dyn Fn() -> T) -> &mut Self {
-        self.take(expected.eq(&actual()), THEORY_IS_TRUE, THEORY_IS_FALSE)
+        let actual: T = self.timed(actual);
+        self.take_as(
+            "theorem",
+            expected.eq(&actual),
+            THEORY_IS_TRUE,
+            THEORY_IS_FALSE,
+        )
     }
+    #[track_caller]
     fn theory<T: PartialEq>(&mut self, expected: T, callback: &dyn Fn() -> T) -> &mut Self {
-        self.take(
-            expected == callback(),
+        let actual: T = self.timed(callback);
+        self.take_as(
+            "theory",
+            expected == actual,
+            ASSERT_THEORY_IS_TRUE,
+            ASSERT_THEORY_SHOULD_BE_TRUE,
+        )
+    }
+
+    #[track_caller]
+    fn theory_async<T: PartialEq, F: Future<Output = T>>(
+        &mut self,
+        expected: T,
+        future: F,
+    ) -> &mut Self {
+        self.take_async(
+            "theory_async",
+            future,
+            &expected,
             ASSERT_THEORY_IS_TRUE,
             ASSERT_THEORY_SHOULD_BE_TRUE,
         )
     }
+
+    #[track_caller]
+    fn for_all<G: Generator>(
+        &mut self,
+        generator: G,
+        property: &dyn Fn(G::Value) -> bool,
+    ) -> &mut Self {
+        match self.timed(|| property::check(&Config::from_env(), &generator, property)) {
+            Ok(_) => self.take_as(
+                "for_all",
+                true,
+                ASSERT_THEORY_IS_TRUE,
+                ASSERT_THEORY_SHOULD_BE_TRUE,
+            ),
+            Err(counterexample) => {
+                let e: String = counterexample.message(ASSERT_THEORY_SHOULD_BE_TRUE);
+                self.take_as("for_all", false, ASSERT_THEORY_IS_TRUE, &e)
+            }
+        }
+    }
 }
 
 impl Failure for Assert {
+    #[track_caller]
     fn command_fail(
         &mut self,
         callbacks: Vec<&dyn Fn() -> Result<ExitStatus, io::Error>>,
     ) -> &mut Self {
         for &c in &callbacks {
-            let status = c().unwrap();
-            self.check(!status.success(), ASSERT_FAIL, ASSERT_SHOULD_BE_FAIL);
+            match self.timed(c) {
+                Ok(status) => self.check_as(
+                    "command_fail",
+                    !status.success(),
+                    ASSERT_FAIL,
+                    ASSERT_SHOULD_BE_FAIL,
+                ),
+                Err(e) => self.check_as(
+                    "command_fail",
+                    false,
+                    ASSERT_FAIL,
+                    &format!("{IS_SPAWN_FAILED}: {e}"),
+                ),
+            }
         }
         self
     }
 
+    #[track_caller]
     fn fail(&mut self, callbacks: Vec<&dyn Fn() -> bool>) -> &mut Self {
         for &c in &callbacks {
-            self.check(!c(), ASSERT_FAIL, ASSERT_SHOULD_BE_FAIL);
+            let t: bool = !self.timed(c);
+            self.check_as("fail", t, ASSERT_FAIL, ASSERT_SHOULD_BE_FAIL);
         }
         self
     }
@@ -103,48 +405,40 @@ impl Take for Assert {
         self.assert(t)
     }
 
+    #[track_caller]
     fn take(&mut self, t: bool, s: &str, e: &str) -> &mut Self {
-        let i: Instant = Instant::now();
-
-        if self.assert_that(t) {
-            assert_eq!(self.messages.insert(self.c.get(), s.to_string()), None);
-            assert_eq!(self.take.insert(self.c.get(), i.elapsed().as_nanos()), None);
-        } else {
-            panic!("{}", format_args!("{s} match {e}"))
-        }
-        self
+        self.take_as("take", t, s, e)
     }
 
+    #[track_caller]
     fn check(&mut self, t: bool, s: &str, e: &str) {
-        let i: Instant = Instant::now();
-
-        if self.assert_that(t) {
-            assert!(self.messages.insert(self.c.get(), s.to_string()).is_some());
-            assert!(self
-                .take
-                .insert(self.c.get(), i.elapsed().as_nanos())
-                .is_some());
-        } else {
-            panic!("{}", format_args!("{s} match {e}"))
-        }
+        self.check_as("check", t, s, e);
     }
 }
 
 impl Testable for Assert {
+    #[track_caller]
     fn matches(&mut self, pattern: &str, values: Vec<String>) -> &mut Self {
         let r = Regex::new(pattern).unwrap();
 
         for x in &values {
-            self.check(r.is_match(x.as_str()), ASSERT_MATCH, ASSERT_SHOULD_MATCH);
+            self.check_as(
+                "matches",
+                r.is_match(x.as_str()),
+                ASSERT_MATCH,
+                ASSERT_SHOULD_MATCH,
+            );
         }
         self
     }
 
+    #[track_caller]
     fn capture(&mut self, pattern: &str, x: &str, key: usize, values: Vec<String>) -> &mut Self {
         let r = Regex::new(pattern).unwrap();
         let caps = r.captures(x).unwrap();
         for v in &values {
-            self.check(
+            self.check_as(
+                "capture",
                 caps.get(key)
                     .expect("failed to get key")
                     .as_str()
@@ -162,26 +456,17 @@ impl Testable for Assert {
         sleep_time: u64,
         callbacks: Vec<&dyn Fn(&mut Self) -> &mut Self>,
     ) {
-        println!("\n{}\n", description.white().bold());
-        println!(
-            "     {}",
-            format_args!("{} {}", "[ + ]".green().bold(), title.blue().bold())
-        );
-        let mut x = Self::new(sleep_time);
-
-        let mut j = &mut x;
-        for &c in &callbacks {
-            j = c(j);
-        }
-        assert!(j.end());
+        let _ = Self::new(sleep_time).run_it(title, description, callbacks);
     }
 
+    #[track_caller]
     fn ok(&mut self, f: bool) -> &mut Self {
-        self.take(f, ASSERT_OK, ASSERT_SHOULD_BE_OK)
+        self.take_as("ok", f, ASSERT_OK, ASSERT_SHOULD_BE_OK)
     }
 
+    #[track_caller]
     fn ko(&mut self, f: bool) -> &mut Self {
-        self.take(!f, ASSERT_KO, ASSERT_SHOULD_BE_KO)
+        self.take_as("ko", !f, ASSERT_KO, ASSERT_SHOULD_BE_KO)
     }
 
     fn assert(&mut self, test: bool) -> bool {
@@ -190,64 +475,199 @@ impl Testable for Assert {
         true
     }
 
-    fn eq<T: PartialEq>(&mut self, a: T, b: T) -> &mut Self {
-        self.take(a.eq(&b), ASSERT_EQUALS, ASSERT_SHOULD_BE_EQUALS)
+    #[track_caller]
+    fn expect<T: ?Sized, M: Matcher<T>>(&mut self, actual: &T, matcher: M) -> &mut Self {
+        let (t, s, e): (bool, String, String) = matcher::check(actual, &matcher);
+        self.take_as("expect", t, &s, &e)
+    }
+
+    #[track_caller]
+    fn eq<T: PartialEq + Debug>(&mut self, a: T, b: T) -> &mut Self {
+        let (t, s, e): (bool, String, String) = matcher::check(&a, &matcher::eq(b));
+        self.take_as("eq", t, &s, &e)
+    }
+
+    #[track_caller]
+    fn eq_debug<T: PartialEq + Debug>(&mut self, a: T, b: T) -> &mut Self {
+        self.values(&format!("{b:#?}"), &format!("{a:#?}")).take_as(
+            "eq_debug",
+            a.eq(&b),
+            ASSERT_EQUALS,
+            ASSERT_SHOULD_BE_EQUALS,
+        )
+    }
+
+    #[track_caller]
+    fn approx_eq<T: Approx + Debug + ?Sized>(
+        &mut self,
+        actual: &T,
+        expected: &T,
+        tolerance: Tolerance,
+    ) -> &mut Self {
+        let result: Result<(), String> = actual.approx(expected, tolerance);
+        let e: String = format!(
+            "{ASSERT_SHOULD_BE_APPROX_EQUALS}: {}",
+            result.as_ref().err().map_or("", String::as_str)
+        );
+        self.values(&format!("{expected:#?}"), &format!("{actual:#?}"))
+            .take_as("approx_eq", result.is_ok(), ASSERT_APPROX_EQUALS, &e)
+    }
+
+    #[track_caller]
+    fn eq_async<T: PartialEq, F: Future<Output = T>>(&mut self, a: F, b: T) -> &mut Self {
+        self.take_async("eq_async", a, &b, ASSERT_EQUALS, ASSERT_SHOULD_BE_EQUALS)
+    }
+
+    #[track_caller]
+    fn within<F: FnOnce() -> bool + Send + 'static>(
+        &mut self,
+        timeout: Duration,
+        callback: F,
+    ) -> &mut Self {
+        match self.timed(|| executor::within(Some(timeout), callback)) {
+            Some(Ok(t)) => self.take_as("within", t, ASSERT_OK, ASSERT_SHOULD_BE_OK),
+            Some(Err(_)) => self.take_as("within", false, ASSERT_OK, ASSERT_PANIC),
+            None => self.take_as("within", false, ASSERT_OK, &executor::timed_out(timeout)),
+        }
+    }
+
+    #[track_caller]
+    fn snapshot(&mut self, name: &str, value: &str) -> &mut Self {
+        match snapshot::check(Location::caller().file(), name, value)
+            .unwrap_or_else(|e| panic!("The snapshot {name} can't be checked: {e}"))
+        {
+            Snapshot::Changed(expected) => self.values(&expected, value).take_as(
+                "snapshot",
+                false,
+                IS_SNAPSHOT_MATCH,
+                IS_SNAPSHOT_CHANGED,
+            ),
+            Snapshot::New => self.take_as("snapshot", false, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_NEW),
+            Snapshot::Matched | Snapshot::Accepted => {
+                self.take_as("snapshot", true, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_CHANGED)
+            }
+        }
+    }
+
+    #[track_caller]
+    fn command(&mut self, command: &Command) -> &mut Self {
+        let file: &str = Location::caller().file();
+        for check in self.timed(|| command.verify(file)) {
+            if let Some((expected, actual)) = &check.values {
+                self.values(expected, actual);
+            }
+            self.take_as(&check.kind, check.passed, &check.success, &check.error);
+        }
+        self
+    }
+
+    #[track_caller]
+    fn str_eq(&mut self, actual: &str, expected: &str) -> &mut Self {
+        self.values(expected, actual).take_as(
+            "str_eq",
+            actual.eq(expected),
+            ASSERT_EQUALS,
+            ASSERT_SHOULD_BE_EQUALS,
+        )
+    }
+
+    #[track_caller]
+    fn ne_debug<T: PartialEq + Debug>(&mut self, a: T, b: T) -> &mut Self {
+        self.values(&format!("{b:#?}"), &format!("{a:#?}")).take_as(
+            "ne_debug",
+            a.ne(&b),
+            ASSERT_UNEQUALS,
+            ASSERT_SHOULD_BE_UNEQUALS,
+        )
     }
 
+    #[track_caller]
     fn ne<T: PartialEq>(&mut self, a: T, b: T) -> &mut Self {
-        self.take(a.ne(&b), ASSERT_UNEQUALS, ASSERT_SHOULD_BE_UNEQUALS)
+        self.take_as("ne", a.ne(&b), ASSERT_UNEQUALS, ASSERT_SHOULD_BE_UNEQUALS)
     }
+    #[track_caller]
     fn gt<T: PartialOrd>(&mut self, a: T, min: T) -> &mut Self {
-        self.take(a.gt(&min), ASSERT_SUPERIOR, ASSERT_SHOULD_BE_SUPERIOR)
+        self.take_as("gt", a.gt(&min), ASSERT_SUPERIOR, ASSERT_SHOULD_BE_SUPERIOR)
     }
+    #[track_caller]
     fn ge<T: PartialOrd>(&mut self, a: T, min: T) -> &mut Self {
-        self.take(a.ge(&min), ASSERT_SUPERIOR, ASSERT_SHOULD_BE_SUPERIOR)
+        self.take_as("ge", a.ge(&min), ASSERT_SUPERIOR, ASSERT_SHOULD_BE_SUPERIOR)
     }
+    #[track_caller]
     fn le<T: PartialOrd>(&mut self, a: T, min: T) -> &mut Self {
-        self.take(a.le(&min), ASSERT_SUPERIOR, ASSERT_SHOULD_BE_SUPERIOR)
+        self.take_as("le", a.le(&min), ASSERT_SUPERIOR, ASSERT_SHOULD_BE_SUPERIOR)
     }
+    #[track_caller]
     fn lt<T: PartialOrd>(&mut self, a: T, min: T) -> &mut Self {
-        self.take(a.lt(&min), ASSERT_SUPERIOR, ASSERT_SHOULD_BE_SUPERIOR)
+        self.take_as("lt", a.lt(&min), ASSERT_SUPERIOR, ASSERT_SHOULD_BE_SUPERIOR)
     }
 
-    fn between<T: PartialOrd>(&mut self, a: T, min: T, max: T) -> &mut Self {
-        self.take(a > min && a < max, ASSERT_BETWEEN, ASSERT_SHOULD_BE_BETWEEN)
+    #[track_caller]
+    fn between<T: PartialOrd + Debug>(&mut self, a: T, min: T, max: T) -> &mut Self {
+        let (t, s, e): (bool, String, String) = matcher::check(&a, &matcher::between(min, max));
+        self.take_as("between", t, &s, &e)
     }
 
+    #[track_caller]
     fn vec_contains<T: PartialEq>(&mut self, a: Vec<T>, b: T) -> &mut Self {
-        self.take(a.contains(&b), ASSERT_CONTAINS, ASSERT_SHOULD_CONTAINS)
+        self.take_as(
+            "vec_contains",
+            a.contains(&b),
+            ASSERT_CONTAINS,
+            ASSERT_SHOULD_CONTAINS,
+        )
     }
 
+    #[track_caller]
     fn exe(&mut self, p: &str) -> &mut Self {
-        self.take(
+        self.take_as(
+            "exe",
             Path::new(p).is_executable(),
             ASSERT_IS_EXECUTABLE,
             ASSERT_SHOULD_BE_EXECUTABLE,
         )
     }
 
+    #[track_caller]
     fn vec_no_contains<T: PartialEq>(&mut self, a: Vec<T>, b: T) -> &mut Self {
-        self.take(
+        self.take_as(
+            "vec_no_contains",
             !a.contains(&b),
             ASSERT_NOT_CONTAINS,
             ASSERT_SHOULD_BE_NOT_CONTAINS,
         )
     }
 
+    #[track_caller]
     fn option_contains<T: PartialEq>(&mut self, a: Option<T>, b: T) -> &mut Self {
-        self.take(a.expect("") == b, ASSERT_CONTAINS, ASSERT_SHOULD_CONTAINS)
+        self.take_as(
+            "option_contains",
+            a.expect("") == b,
+            ASSERT_CONTAINS,
+            ASSERT_SHOULD_CONTAINS,
+        )
     }
 
+    #[track_caller]
     fn hash_contains(&mut self, a: &mut HashSet<String>, b: String) -> &mut Self {
-        self.take(a.contains(&b), ASSERT_CONTAINS, ASSERT_SHOULD_CONTAINS)
+        self.take_as(
+            "hash_contains",
+            a.contains(&b),
+            ASSERT_CONTAINS,
+            ASSERT_SHOULD_CONTAINS,
+        )
     }
 
+    #[track_caller]
     fn str_contains(&mut self, a: &str, b: &str) -> &mut Self {
-        self.take(a.contains(b), ASSERT_CONTAINS, ASSERT_SHOULD_CONTAINS)
+        let (t, s, e): (bool, String, String) = matcher::check(a, &matcher::str_contains(b));
+        self.values(b, a).take_as("str_contains", t, &s, &e)
     }
 
+    #[track_caller]
     fn file_contains(&mut self, f: &str, v: &str) -> &mut Self {
-        self.take(
+        self.take_as(
+            "file_contains",
             fs::read_to_string(f)
                 .unwrap_or_else(|_| panic!("The filename {f} has not been founded"))
                 .contains(v),
@@ -256,32 +676,40 @@ impl Testable for Assert {
         )
     }
 
+    #[track_caller]
     fn exists(&mut self, p: &str) -> &mut Self {
-        self.take(
+        self.take_as(
+            "exists",
             Path::new(p).exists(),
             ASSERT_EXISTS,
             ASSERT_SHOULD_BE_EXISTS,
         )
     }
 
+    #[track_caller]
     fn not_exists(&mut self, p: &str) -> &mut Self {
-        self.take(
+        self.take_as(
+            "not_exists",
             !Path::new(p).exists(),
             ASSERT_NOT_EXISTS,
             ASSERT_SHOULD_BE_NOT_EXISTS,
         )
     }
 
+    #[track_caller]
     fn start_with(&mut self, actual: &str, expected: &str) -> &mut Self {
-        self.take(
+        self.values(expected, actual).take_as(
+            "start_with",
             actual.starts_with(expected),
             ASSERT_BEGIN,
             ASSERT_SHOULD_BE_BEGIN,
         )
     }
 
+    #[track_caller]
     fn end_with(&mut self, actual: &str, expected: &str) -> &mut Self {
-        self.take(
+        self.values(expected, actual).take_as(
+            "end_with",
             actual.ends_with(expected),
             ASSERT_FINNISH,
             ASSERT_SHOULD_BE_FINNISH,
@@ -289,62 +717,41 @@ impl Testable for Assert {
     }
 
     fn end(&mut self) -> bool {
-        let total: usize = self.c.get();
-        init_progress_bar(total);
-        set_progress_bar_action("[ ✓ ]", Color::Green, Style::Bold);
-
-        let mut take = self.take.values();
-        let mut messages = self.messages.values();
-        for _i in 0..total {
-            sleep(Duration::from_millis(self.sleep));
-            print_progress_bar_info(
-                "[ ✓ ]",
-                format!(
-                    "{} {} {} {}",
-                    messages.next().unwrap().to_string().blue().bold(),
-                    "take".white().bold(),
-                    take.next().unwrap().to_string().cyan().bold(),
-                    "ns".blue().bold()
-                )
-                .as_str(),
-                Color::Green,
-                Style::Bold,
-            );
-            inc_progress_bar();
+        if let Err(e) = reporter::finish(&mut self.reporters, &self.report) {
+            let e: String = format!("{IS_REPORT_NOT_WRITTEN}: {e}");
+            self.check_as("report", false, IS_REPORT_WRITTEN, &e);
         }
-
-        print_progress_bar_final_info(
-            "[ ✓ ]",
-            format!(
-                "{} {}",
-                total.to_string().blue().bold(),
-                "assertions".blue().bold()
-            )
-            .as_str(),
-            Color::Green,
-            Style::Bold,
-        );
-        finalize_progress_bar();
         true
     }
 
+    fn report(&self) -> Report {
+        self.report.clone()
+    }
+
     fn new(sleep_time: u64) -> Self {
         Self {
             c: Cell::new(0),
-            sleep: sleep_time,
-            messages: HashMap::new(),
-            take: HashMap::new(),
+            report: Report::default(),
+            reporters: reporter::progress(sleep_time, true),
+            values: None,
+            clock: None,
+            timeout: None,
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::approx::Tolerance;
     use crate::assert_that;
     use crate::assertions::Assert;
+    use crate::matcher;
     use crate::objects::{Testable, Theory};
-    use crate::output::DISABLE_PROGRESS_TIME;
+    use crate::output::{ASSERT_EQUALS, DISABLE_PROGRESS_TIME};
+    use crate::property::{self, any, between};
+    use crate::reporter::Recorder;
     use std::collections::HashSet;
+    use std::time::Duration;
 
     fn ok() -> bool {
         true
@@ -426,6 +833,107 @@ mod test {
         u.theory(5.0, &pythagore).chaos(&pythagore_not_work)
     }
 
+    #[test]
+    pub fn futures() {
+        let mut assert = Assert::new(DISABLE_PROGRESS_TIME);
+        assert
+            .timeout(Duration::from_millis(50))
+            .eq_async(async { 4 }, 4)
+            .theory_async(5.0, async { pythagore() });
+        assert_eq!(assert.report().assertions(), 2);
+        assert!(std::panic::catch_unwind(|| {
+            Assert::new(DISABLE_PROGRESS_TIME).eq_async(async { 3 }, 4);
+        })
+        .is_err());
+        assert!(std::panic::catch_unwind(|| {
+            Assert::new(DISABLE_PROGRESS_TIME)
+                .timeout(Duration::from_millis(50))
+                .eq_async(std::future::pending::<i32>(), 4);
+        })
+        .is_err());
+    }
+
+    #[test]
+    pub fn approx() {
+        let mut assert = Assert::new(DISABLE_PROGRESS_TIME);
+        assert
+            .approx_eq(&(0.1 + 0.2), &0.3, Tolerance::Ulps(1))
+            .approx_eq(&[3.0_f32.hypot(4.0)], &[5.0], Tolerance::Relative(1e-6))
+            .approx_eq(&f64::INFINITY, &f64::INFINITY, Tolerance::Absolute(0.1));
+        assert_eq!(assert.report().assertions(), 3);
+        let error = std::panic::catch_unwind(|| {
+            Assert::new(DISABLE_PROGRESS_TIME).approx_eq(&1.0, &1.5, Tolerance::Absolute(0.1));
+        })
+        .expect_err("distant values must panic");
+        let message = error.downcast_ref::<String>().cloned().unwrap_or_default();
+        assert!(message.contains("1 and 1.5 differ by 5e-1, the tolerance is absolute 1e-1"));
+    }
+
+    #[test]
+    pub fn matchers() {
+        let mut assert = Assert::new(DISABLE_PROGRESS_TIME);
+        assert
+            .expect(
+                &50,
+                matcher::all_of(vec![Box::new(matcher::gt(10)), Box::new(matcher::le(200))]),
+            )
+            .expect("a-b", matcher::not(matcher::matches("^[0-9]+$")));
+        assert_eq!(assert.report().assertions(), 2);
+        let error = std::panic::catch_unwind(|| {
+            Assert::new(DISABLE_PROGRESS_TIME).expect(&5, matcher::between(5, 9));
+        })
+        .expect_err("an unmatched value must panic");
+        let message = error.downcast_ref::<String>().cloned().unwrap_or_default();
+        assert!(message.ends_with("between 5 and 9, was 5"));
+    }
+
+    #[test]
+    pub fn properties() {
+        let mut assert = Assert::new(DISABLE_PROGRESS_TIME);
+        assert.for_all(any::<(f32, f32)>(), &|(a, b)| a.hypot(b) >= a.abs());
+        assert_eq!(assert.report().assertions(), 1);
+        let error = std::panic::catch_unwind(|| {
+            Assert::new(DISABLE_PROGRESS_TIME).for_all(between(0_u32, 1000), &|x| x < 100);
+        })
+        .expect_err("a falsified property must panic");
+        let message = error.downcast_ref::<String>().cloned().unwrap_or_default();
+        assert!(message.contains(property::SEED));
+    }
+
+    #[test]
+    pub fn values() {
+        let mut assert = Assert::new(DISABLE_PROGRESS_TIME);
+        assert
+            .eq_debug(vec![1, 2], vec![1, 2])
+            .ne_debug(Some(1), None)
+            .str_eq("linux", "linux");
+        assert_eq!(assert.report().assertions(), 3);
+        let error = std::panic::catch_unwind(|| {
+            Assert::new(DISABLE_PROGRESS_TIME).eq_debug(vec![1], vec![2]);
+        })
+        .expect_err("unequal values must panic");
+        let message = error.downcast_ref::<String>().cloned().unwrap_or_default();
+        assert!(message.starts_with(ASSERT_EQUALS));
+        assert!(message.contains("1,") && message.contains("2,"));
+    }
+
+    #[test]
+    pub fn reporters() {
+        let recorder = Recorder::default();
+        let report = Assert::new(DISABLE_PROGRESS_TIME)
+            .with_reporter(Box::new(recorder.clone()))
+            .run_it(
+                "Test the custom reporters",
+                "A reporter added before the run receives all the events",
+                vec![&must_pass],
+            );
+        assert_eq!(report.assertions(), 2);
+        assert_eq!(
+            recorder.events(),
+            vec!["Test the custom reporters", "ok 0", "ko 0"]
+        );
+    }
+
     #[test]
     pub fn all() {
         assert_that!(
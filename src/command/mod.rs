@@ -0,0 +1,709 @@
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::{self, ExitStatus, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+
+use crate::output::{
+    IS_EXIT_CODE, IS_IN_TIME, IS_NOT_EXIT_CODE, IS_NOT_IN_TIME, IS_NOT_OUTPUT, IS_NOT_SIGNAL,
+    IS_OUTPUT, IS_SIGNAL, IS_SNAPSHOT_CHANGED, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_NEW, IS_SPAWN_FAILED,
+};
+use crate::snapshot::{self, Snapshot};
+
+///
+/// # The time left to the streams of a killed command to close
+///
+const GRACE: Duration = Duration::from_millis(100);
+
+///
+/// # The expected content of an output stream
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Content {
+    /// The stream equals the value
+    Equals(String),
+    /// The stream contains the value
+    Contains(String),
+    /// The stream matches the regex
+    Matches(String),
+    /// The stream matches the named snapshot
+    Snapshot(String),
+}
+
+///
+/// # An expectation on a finished command
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Expectation {
+    /// The exact exit code
+    Code(i32),
+    /// The signal terminating the command
+    #[cfg(unix)]
+    Signal(i32),
+    /// The content of the stdout
+    Stdout(Content),
+    /// The content of the stderr
+    Stderr(Content),
+    /// The maximum run duration
+    Within(Duration),
+}
+
+///
+/// # The result of a command expectation
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+    /// The assertion kind
+    pub kind: String,
+    /// The expectation is met
+    pub passed: bool,
+    /// The success message
+    pub success: String,
+    /// The error message
+    pub error: String,
+    /// The expected and the actual values
+    pub values: Option<(String, String)>,
+}
+
+impl Check {
+    ///
+    /// # Initialize a check
+    ///
+    /// - `kind` The assertion kind
+    /// - `passed` The expectation is met
+    /// - `success` The success message
+    /// - `error` The error message
+    ///
+    fn new(kind: &str, passed: bool, success: &str, error: &str) -> Self {
+        Self {
+            kind: kind.to_string(),
+            passed,
+            success: success.to_string(),
+            error: error.to_string(),
+            values: None,
+        }
+    }
+
+    ///
+    /// # Attach the expected and the actual values
+    ///
+    /// - `expected` The expected value
+    /// - `actual` The actual value
+    ///
+    fn with_values(mut self, expected: &str, actual: &str) -> Self {
+        self.values = Some((expected.to_string(), actual.to_string()));
+        self
+    }
+}
+
+///
+/// # The output of a finished command
+///
+#[derive(Clone, Debug)]
+pub struct Output {
+    /// The exit status
+    pub status: ExitStatus,
+    /// The standard output
+    pub stdout: String,
+    /// The error output
+    pub stderr: String,
+    /// The run duration
+    pub duration: Duration,
+    /// The command was killed at its deadline
+    pub killed: bool,
+}
+
+///
+/// # A command to run and its expectations
+///
+#[derive(Clone, Debug, Default)]
+pub struct Command {
+    program: String,
+    args: Vec<String>,
+    envs: Vec<(String, String)>,
+    stdin: Option<String>,
+    directory: Option<PathBuf>,
+    expectations: Vec<Expectation>,
+}
+
+impl Command {
+    ///
+    /// # Add an argument
+    ///
+    /// - `arg` The argument
+    ///
+    #[must_use]
+    pub fn arg(mut self, arg: &str) -> Self {
+        self.args.push(arg.to_string());
+        self
+    }
+
+    ///
+    /// # Add arguments
+    ///
+    /// - `args` The arguments
+    ///
+    #[must_use]
+    pub fn args(mut self, args: &[&str]) -> Self {
+        self.args.extend(args.iter().map(ToString::to_string));
+        self
+    }
+
+    ///
+    /// # Set an environment variable
+    ///
+    /// - `key` The variable name
+    /// - `value` The variable value
+    ///
+    #[must_use]
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.envs.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    ///
+    /// # Write a text on the standard input
+    ///
+    /// - `input` The text
+    ///
+    #[must_use]
+    pub fn stdin(mut self, input: &str) -> Self {
+        self.stdin = Some(input.to_string());
+        self
+    }
+
+    ///
+    /// # Set the working directory
+    ///
+    /// - `directory` The directory
+    ///
+    #[must_use]
+    pub fn current_dir(mut self, directory: &str) -> Self {
+        self.directory = Some(PathBuf::from(directory));
+        self
+    }
+
+    ///
+    /// # Expect an exit code
+    ///
+    /// - `code` The exit code
+    ///
+    #[must_use]
+    pub fn code(self, code: i32) -> Self {
+        self.expect(Expectation::Code(code))
+    }
+
+    ///
+    /// # Expect a termination by a signal
+    ///
+    /// Only unix platforms report the signal terminating a process.
+    ///
+    /// - `signal` The signal number
+    ///
+    #[cfg(unix)]
+    #[must_use]
+    pub fn signal(self, signal: i32) -> Self {
+        self.expect(Expectation::Signal(signal))
+    }
+
+    ///
+    /// # Expect a standard output
+    ///
+    /// - `expected` The exact output
+    ///
+    #[must_use]
+    pub fn stdout_eq(self, expected: &str) -> Self {
+        self.expect(Expectation::Stdout(Content::Equals(expected.to_string())))
+    }
+
+    ///
+    /// # Expect a standard output containing a value
+    ///
+    /// - `needle` The contained value
+    ///
+    #[must_use]
+    pub fn stdout_contains(self, needle: &str) -> Self {
+        self.expect(Expectation::Stdout(Content::Contains(needle.to_string())))
+    }
+
+    ///
+    /// # Expect a standard output matching a regex
+    ///
+    /// - `pattern` The regex
+    ///
+    #[must_use]
+    pub fn stdout_matches(self, pattern: &str) -> Self {
+        self.expect(Expectation::Stdout(Content::Matches(pattern.to_string())))
+    }
+
+    ///
+    /// # Expect a standard output matching a snapshot
+    ///
+    /// - `name` The snapshot name
+    ///
+    #[must_use]
+    pub fn stdout_snapshot(self, name: &str) -> Self {
+        self.expect(Expectation::Stdout(Content::Snapshot(name.to_string())))
+    }
+
+    ///
+    /// # Expect an error output
+    ///
+    /// - `expected` The exact output
+    ///
+    #[must_use]
+    pub fn stderr_eq(self, expected: &str) -> Self {
+        self.expect(Expectation::Stderr(Content::Equals(expected.to_string())))
+    }
+
+    ///
+    /// # Expect an error output containing a value
+    ///
+    /// - `needle` The contained value
+    ///
+    #[must_use]
+    pub fn stderr_contains(self, needle: &str) -> Self {
+        self.expect(Expectation::Stderr(Content::Contains(needle.to_string())))
+    }
+
+    ///
+    /// # Expect an error output matching a regex
+    ///
+    /// - `pattern` The regex
+    ///
+    #[must_use]
+    pub fn stderr_matches(self, pattern: &str) -> Self {
+        self.expect(Expectation::Stderr(Content::Matches(pattern.to_string())))
+    }
+
+    ///
+    /// # Expect an error output matching a snapshot
+    ///
+    /// - `name` The snapshot name
+    ///
+    #[must_use]
+    pub fn stderr_snapshot(self, name: &str) -> Self {
+        self.expect(Expectation::Stderr(Content::Snapshot(name.to_string())))
+    }
+
+    ///
+    /// # Expect the command to end before a duration
+    ///
+    /// The command is killed when the duration expires, the output written
+    /// so far is kept even when a child process still holds the streams.
+    ///
+    /// - `duration` The maximum run duration
+    ///
+    #[must_use]
+    pub fn within(self, duration: Duration) -> Self {
+        self.expect(Expectation::Within(duration))
+    }
+
+    ///
+    /// # Add an expectation
+    ///
+    /// - `expectation` The expectation
+    ///
+    fn expect(mut self, expectation: Expectation) -> Self {
+        self.expectations.push(expectation);
+        self
+    }
+
+    ///
+    /// # Get the shortest expected duration
+    ///
+    fn deadline(&self) -> Option<Duration> {
+        self.expectations
+            .iter()
+            .filter_map(|e| match e {
+                Expectation::Within(duration) => Some(*duration),
+                _ => None,
+            })
+            .min()
+    }
+
+    ///
+    /// # Run the command and capture its output
+    ///
+    /// A command still running after the shortest `within` duration is killed.
+    ///
+    /// # Errors
+    ///
+    /// When the command can't be spawned or waited
+    ///
+    pub fn output(&self) -> io::Result<Output> {
+        let mut command = process::Command::new(&self.program);
+        command
+            .args(&self.args)
+            .envs(self.envs.iter().map(|(k, v)| (k, v)))
+            .stdin(if self.stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if let Some(directory) = &self.directory {
+            command.current_dir(directory);
+        }
+        let start: Instant = Instant::now();
+        let mut child = command.spawn()?;
+        let writer = match (child.stdin.take(), self.stdin.clone()) {
+            (Some(mut stdin), Some(input)) => {
+                Some(thread::spawn(move || stdin.write_all(input.as_bytes())))
+            }
+            _ => None,
+        };
+        let (stdout, stderr) = (drain(child.stdout.take()), drain(child.stderr.take()));
+        let mut killed: bool = false;
+        let status: ExitStatus = match self.deadline() {
+            None => child.wait()?,
+            Some(deadline) => loop {
+                if let Some(status) = child.try_wait()? {
+                    break status;
+                }
+                if start.elapsed().ge(&deadline) {
+                    killed = true;
+                    child.kill()?;
+                    break child.wait()?;
+                }
+                thread::sleep(Duration::from_millis(5).min(deadline));
+            },
+        };
+        let duration: Duration = start.elapsed();
+        let until: Option<Instant> = killed.then(|| Instant::now() + GRACE);
+        if let Some(writer) = writer.filter(|w| until.is_none() || w.is_finished()) {
+            match writer.join() {
+                Ok(Err(e)) if e.kind().ne(&io::ErrorKind::BrokenPipe) => return Err(e),
+                _ => {}
+            }
+        }
+        Ok(Output {
+            status,
+            stdout: stdout.collect(until),
+            stderr: stderr.collect(until),
+            duration,
+            killed,
+        })
+    }
+
+    ///
+    /// # Run the command and check its expectations
+    ///
+    /// A command that can't be spawned gives one failed check.
+    ///
+    /// - `file` The test file, the snapshots are stored next to it
+    ///
+    #[must_use]
+    pub fn verify(&self, file: &str) -> Vec<Check> {
+        match self.output() {
+            Ok(output) => self
+                .expectations
+                .iter()
+                .map(|e| check(e, &output, file))
+                .collect(),
+            Err(e) => vec![Check::new(
+                "command",
+                false,
+                IS_SPAWN_FAILED,
+                &format!("{IS_SPAWN_FAILED} {}: {e}", self.program),
+            )],
+        }
+    }
+}
+
+///
+/// # Build a command
+///
+/// - `program` The program to run
+///
+#[must_use]
+pub fn cmd(program: &str) -> Command {
+    Command {
+        program: program.to_string(),
+        ..Command::default()
+    }
+}
+
+///
+/// # Read an output stream of a child on a thread
+///
+/// - `stream` The output stream
+///
+fn drain(stream: Option<impl Read + Send + 'static>) -> Drain {
+    let bytes: Arc<Mutex<Vec<u8>>> = Arc::default();
+    let buffer: Arc<Mutex<Vec<u8>>> = Arc::clone(&bytes);
+    let reader = thread::spawn(move || {
+        if let Some(mut stream) = stream {
+            let mut chunk: [u8; 4096] = [0; 4096];
+            while let Ok(n) = stream.read(&mut chunk) {
+                if n.eq(&0) {
+                    break;
+                }
+                if let Ok(mut bytes) = buffer.lock() {
+                    bytes.extend_from_slice(&chunk[..n]);
+                }
+            }
+        }
+    });
+    Drain { bytes, reader }
+}
+
+///
+/// # A stream read in the background
+///
+struct Drain {
+    /// The bytes read so far
+    bytes: Arc<Mutex<Vec<u8>>>,
+    /// The reading thread
+    reader: thread::JoinHandle<()>,
+}
+
+impl Drain {
+    ///
+    /// # Get the text read from the stream
+    ///
+    /// A reader still blocked at `until` is left behind and the bytes read so far are returned.
+    ///
+    /// - `until` The instant to stop waiting for the end of the stream, none to wait forever
+    ///
+    fn collect(self, until: Option<Instant>) -> String {
+        match until {
+            None => {
+                let _ = self.reader.join();
+            }
+            Some(until) => {
+                while !self.reader.is_finished() && Instant::now().lt(&until) {
+                    thread::sleep(Duration::from_millis(5));
+                }
+            }
+        }
+        self.bytes
+            .lock()
+            .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+            .unwrap_or_default()
+    }
+}
+
+///
+/// # Get the signal terminating a process
+///
+/// - `status` The exit status
+///
+#[cfg(unix)]
+fn terminated_by(status: ExitStatus) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal()
+}
+
+///
+/// # Check an expectation on a command output
+///
+/// - `expectation` The expectation
+/// - `output` The command output
+/// - `file` The test file
+///
+fn check(expectation: &Expectation, output: &Output, file: &str) -> Check {
+    match expectation {
+        Expectation::Code(code) => {
+            let actual: String = output
+                .status
+                .code()
+                .map_or_else(|| String::from("none"), |c| c.to_string());
+            Check::new(
+                "exit_code",
+                output.status.code().eq(&Some(*code)),
+                IS_EXIT_CODE,
+                IS_NOT_EXIT_CODE,
+            )
+            .with_values(&code.to_string(), &actual)
+        }
+        #[cfg(unix)]
+        Expectation::Signal(signal) => {
+            let actual: Option<i32> = terminated_by(output.status);
+            Check::new(
+                "signal",
+                actual.eq(&Some(*signal)),
+                IS_SIGNAL,
+                IS_NOT_SIGNAL,
+            )
+            .with_values(&signal.to_string(), &format!("{actual:?}"))
+        }
+        Expectation::Stdout(content) => stream("stdout", content, &output.stdout, file),
+        Expectation::Stderr(content) => stream("stderr", content, &output.stderr, file),
+        Expectation::Within(duration) if output.killed => Check::new(
+            "duration",
+            false,
+            IS_IN_TIME,
+            &format!(
+                "{IS_NOT_IN_TIME}: killed after {} ms",
+                output.duration.as_millis()
+            ),
+        ),
+        Expectation::Within(duration) => Check::new(
+            "duration",
+            output.duration.le(duration),
+            IS_IN_TIME,
+            &format!(
+                "{IS_NOT_IN_TIME}: {} ms > {} ms",
+                output.duration.as_millis(),
+                duration.as_millis()
+            ),
+        ),
+    }
+}
+
+///
+/// # Check the content of an output stream
+///
+/// - `kind` The stream name
+/// - `content` The expected content
+/// - `actual` The stream content
+/// - `file` The test file
+///
+fn stream(kind: &str, content: &Content, actual: &str, file: &str) -> Check {
+    match content {
+        Content::Equals(expected) => Check::new(
+            kind,
+            actual.eq(expected),
+            IS_OUTPUT,
+            &format!("{IS_NOT_OUTPUT}: {kind} differs"),
+        )
+        .with_values(expected, actual),
+        Content::Contains(needle) => Check::new(
+            kind,
+            actual.contains(needle.as_str()),
+            IS_OUTPUT,
+            &format!("{IS_NOT_OUTPUT}: {kind} don't contain {needle:?}"),
+        ),
+        Content::Matches(pattern) => match Regex::new(pattern) {
+            Ok(regex) => Check::new(
+                kind,
+                regex.is_match(actual),
+                IS_OUTPUT,
+                &format!("{IS_NOT_OUTPUT}: {kind} don't match {pattern:?}"),
+            ),
+            Err(e) => Check::new(kind, false, IS_OUTPUT, &format!("{IS_NOT_OUTPUT}: {e}")),
+        },
+        Content::Snapshot(name) => match snapshot::check(file, name, actual) {
+            Ok(Snapshot::Matched | Snapshot::Accepted) => {
+                Check::new(kind, true, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_CHANGED)
+            }
+            Ok(Snapshot::New) => Check::new(kind, false, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_NEW),
+            Ok(Snapshot::Changed(expected)) => {
+                Check::new(kind, false, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_CHANGED)
+                    .with_values(&expected, actual)
+            }
+            Err(e) => Check::new(
+                kind,
+                false,
+                IS_SNAPSHOT_MATCH,
+                &format!("The snapshot {name} can't be checked: {e}"),
+            ),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::command::cmd;
+    use crate::output::{IS_NOT_IN_TIME, IS_SPAWN_FAILED};
+    use std::io;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn child() {
+        match std::env::var("UNIT_TESTING_CHILD").as_deref() {
+            Ok("echo") => {
+                let mut line = String::new();
+                let _ = io::stdin().read_line(&mut line);
+                println!("echo {}", line.trim());
+                eprintln!("oops");
+                std::process::exit(3);
+            }
+            Ok("hang") => std::thread::sleep(Duration::from_secs(60)),
+            _ => {}
+        }
+    }
+
+    fn this(mode: &str) -> crate::command::Command {
+        let exe = std::env::current_exe().map(|p| p.to_string_lossy().to_string());
+        cmd(&exe.unwrap_or_default())
+            .args(&["--exact", "command::test::child", "--nocapture"])
+            .env("UNIT_TESTING_CHILD", mode)
+    }
+
+    fn passed(checks: &[crate::command::Check]) -> Vec<(&str, bool)> {
+        checks.iter().map(|c| (c.kind.as_str(), c.passed)).collect()
+    }
+
+    #[test]
+    fn verify() {
+        let checks = this("echo")
+            .stdin("hello\n")
+            .current_dir(".")
+            .code(3)
+            .stdout_contains("echo hello")
+            .stdout_matches("(?m)echo hello$")
+            .stderr_contains("oops")
+            .within(Duration::from_secs(30))
+            .code(0)
+            .verify(file!());
+        assert_eq!(
+            passed(&checks),
+            vec![
+                ("exit_code", true),
+                ("stdout", true),
+                ("stdout", true),
+                ("stderr", true),
+                ("duration", true),
+                ("exit_code", false),
+            ]
+        );
+        let missing = cmd("./does-not-exist").code(0).verify(file!());
+        assert_eq!(missing.len(), 1);
+        assert!(!missing[0].passed);
+        assert!(missing[0].error.starts_with(IS_SPAWN_FAILED));
+    }
+
+    #[test]
+    fn deadline() {
+        let start = Instant::now();
+        let checks = this("hang")
+            .within(Duration::from_millis(200))
+            .verify(file!());
+        assert!(start.elapsed() < Duration::from_secs(30));
+        assert_eq!(passed(&checks), vec![("duration", false)]);
+        assert!(checks[0].error.starts_with(IS_NOT_IN_TIME));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn orphan() {
+        let start = Instant::now();
+        let output = cmd("sh")
+            .args(&["-c", "echo started; sleep 60; echo done"])
+            .within(Duration::from_millis(200))
+            .output()
+            .expect("sh should spawn");
+        assert!(start.elapsed() < Duration::from_secs(30));
+        assert!(output.killed);
+        assert_eq!(output.stdout, "started\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn shell() {
+        let checks = cmd("sh")
+            .args(&["-c", "read line; echo \"$line $GREETING\"; kill -9 $$"])
+            .env("GREETING", "world")
+            .stdin("hello\n")
+            .stdout_eq("hello world\n")
+            .signal(9)
+            .verify(file!());
+        assert_eq!(passed(&checks), vec![("stdout", true), ("signal", true)]);
+    }
+}
@@ -0,0 +1,586 @@
+use std::io::IsTerminal;
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+use colored_truecolor::Colorize;
+use progress_bar::{
+    finalize_progress_bar, inc_progress_bar, init_progress_bar_with_eta,
+    print_progress_bar_final_info, print_progress_bar_info, set_progress_bar_action, Color, Style,
+};
+use regex::Regex;
+
+use crate::output::SUITE_PROGRESS_TIME;
+use crate::pacing::{self, Pacing};
+use crate::report::{Outcome, Record, Report};
+use crate::reporter::Reporter;
+
+///
+/// # The environment variable containing the console backend
+///
+/// The values are `fancy`, `plain` or `quiet`.
+///
+pub const CONSOLE: &str = "UNIT_TESTING_CONSOLE";
+
+///
+/// # The backend set by `set`, read before the environment
+///
+static CURRENT: RwLock<Option<Console>> = RwLock::new(None);
+
+///
+/// # The backend writing the human readable output
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Console {
+    /// Colours and progress bars
+    Fancy,
+    /// No colours, one line per assertion
+    Plain,
+    /// Only the failures and the summary
+    Quiet,
+}
+
+impl Console {
+    ///
+    /// # Parse a backend name
+    ///
+    /// - `console` The backend name
+    ///
+    #[must_use]
+    pub fn parse(console: &str) -> Option<Self> {
+        match console.trim() {
+            "fancy" => Some(Self::Fancy),
+            "plain" => Some(Self::Plain),
+            "quiet" => Some(Self::Quiet),
+            _ => None,
+        }
+    }
+
+    ///
+    /// # Read the backend from the `UNIT_TESTING_CONSOLE` environment variable
+    ///
+    /// Without it, the backend is `Plain` when the stdout is not a terminal or `NO_COLOR` is defined,
+    /// `Fancy` otherwise.
+    ///
+    #[must_use]
+    pub fn from_env() -> Self {
+        if let Some(console) = std::env::var(CONSOLE).ok().and_then(|c| Self::parse(&c)) {
+            return console;
+        }
+        if std::env::var("NO_COLOR").is_ok_and(|c| !c.is_empty())
+            || !std::io::stdout().is_terminal()
+        {
+            Self::Plain
+        } else {
+            Self::Fancy
+        }
+    }
+
+    ///
+    /// # Print the header of a run
+    ///
+    /// - `title` The run title
+    /// - `description` The run description
+    ///
+    pub fn header(&self, title: &str, description: &str) {
+        match self {
+            Self::Fancy => println!("\n{title}\n\n{description}\n"),
+            Self::Plain => println!("{title}: {description}"),
+            Self::Quiet => {}
+        }
+    }
+
+    ///
+    /// # Print the title of a group
+    ///
+    /// - `title` The group title
+    /// - `description` The group description
+    /// - `depth` The group depth
+    ///
+    pub fn title(&self, title: &str, description: &str, depth: usize) {
+        match self {
+            Self::Fancy => print(depth, &format!("\n{title}\n\n\t{description}\n")),
+            Self::Plain => print(depth, &format!("{title}: {description}")),
+            Self::Quiet => {}
+        }
+    }
+
+    ///
+    /// # Print the title of a theory
+    ///
+    /// - `title` The theory title
+    /// - `description` The theory description
+    /// - `depth` The depth of the group containing the theory
+    ///
+    pub fn sub_title(&self, title: &str, description: &str, depth: usize) {
+        match self {
+            Self::Fancy => print(depth, &format!("\t{title}\n\n\t{description}\n")),
+            Self::Plain => print(depth, &format!("\t{title}: {description}")),
+            Self::Quiet => {}
+        }
+    }
+
+    ///
+    /// # Print a passed assertion
+    ///
+    /// - `message` The success message
+    /// - `depth` The depth of the group containing the assertion
+    ///
+    pub fn passed(&self, message: &str, depth: usize) {
+        match self {
+            Self::Fancy => print(depth, &format!("\t\t {message}\n")),
+            Self::Plain => print(depth, &format!("[ OK ] {message}")),
+            Self::Quiet => {}
+        }
+    }
+
+    ///
+    /// # Print a failed assertion
+    ///
+    /// - `message` The error message
+    /// - `depth` The depth of the group containing the assertion
+    ///
+    pub fn failed(&self, message: &str, depth: usize) {
+        match self {
+            Self::Fancy => print(depth, &format!("\t\t* {message}\n")),
+            Self::Plain | Self::Quiet => print(depth, &format!("[ KO ] {message}")),
+        }
+    }
+
+    ///
+    /// # Print a skipped assertion
+    ///
+    /// - `message` The output message
+    /// - `depth` The depth of the group containing the assertion
+    ///
+    pub fn skipped(&self, message: &str, depth: usize) {
+        match self {
+            Self::Fancy => print(depth, &format!("\t\t- {message} (skipped)\n")),
+            Self::Plain => print(depth, &format!("[ -- ] {message}")),
+            Self::Quiet => {}
+        }
+    }
+
+    ///
+    /// # Print a todo assertion
+    ///
+    /// - `message` The output message
+    /// - `depth` The depth of the group containing the assertion
+    ///
+    pub fn todo(&self, message: &str, depth: usize) {
+        match self {
+            Self::Fancy => print(depth, &format!("\t\t~ {message} (todo)\n")),
+            Self::Plain => print(depth, &format!("[ ~~ ] {message}")),
+            Self::Quiet => {}
+        }
+    }
+
+    ///
+    /// # Print the location and the diff of a failed assertion
+    ///
+    /// - `record` The failed assertion
+    /// - `depth` The depth of the group containing the assertion
+    ///
+    pub fn details(&self, record: &Record, depth: usize) {
+        match self {
+            Self::Fancy => {
+                if let Some(at) = record.at() {
+                    print(depth, &format!("\t\t  {at}"));
+                }
+                if let Some(diff) = record.diff() {
+                    print(depth, &format!("{diff}\n"));
+                }
+            }
+            Self::Plain | Self::Quiet => {
+                if let Some(at) = record.at() {
+                    print(depth, &format!("       at {at}"));
+                }
+                if let Some(diff) = record.diff() {
+                    print(depth, &uncoloured(&diff));
+                }
+            }
+        }
+    }
+
+    ///
+    /// # Print an assertion
+    ///
+    /// - `record` The assertion
+    /// - `depth` The depth of the group containing the assertion
+    ///
+    pub fn record(&self, record: &Record, depth: usize) {
+        let message: &str = record.message.as_str();
+        match record.outcome {
+            Outcome::Passed => self.passed(message, depth),
+            Outcome::Failed if self.eq(&Self::Fancy) => {
+                self.details(record, depth);
+                self.failed(message, depth);
+            }
+            Outcome::Failed => {
+                self.failed(message, depth);
+                self.details(record, depth);
+            }
+            Outcome::Skipped => self.skipped(message, depth),
+            Outcome::Todo => self.todo(message, depth),
+        }
+    }
+
+    ///
+    /// # Print the summary of a report
+    ///
+    /// - `report` The report
+    ///
+    pub fn summary(&self, report: &Report) {
+        match self {
+            Self::Fancy => println!("\n{}", totals(report)),
+            Self::Plain | Self::Quiet => println!(
+                "Assertions : {} Failures : {} Skipped : {} Todo : {}",
+                report.assertions(),
+                report.failures(),
+                report.skipped(),
+                report.todo()
+            ),
+        }
+    }
+
+    ///
+    /// # Print all the assertions of a report and its summary
+    ///
+    /// - `report` The report
+    ///
+    pub fn report(&self, report: &Report) {
+        for record in &report.records {
+            self.record(record, 0);
+        }
+        self.summary(report);
+    }
+}
+
+///
+/// # The reporter writing the assertions of a suite as they are recorded
+///
+/// The pacing is read once, the animated pacing pauses `SUITE_PROGRESS_TIME` ms after each assertion.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct ConsoleReporter {
+    console: Console,
+    pacing: Pacing,
+}
+
+impl ConsoleReporter {
+    ///
+    /// # Initialize the reporter with the current pacing
+    ///
+    /// - `console` The backend
+    ///
+    #[must_use]
+    pub fn new(console: Console) -> Self {
+        Self::with_pacing(console, pacing::current())
+    }
+
+    ///
+    /// # Initialize the reporter with a pacing
+    ///
+    /// - `console` The backend
+    /// - `pacing` The pause after each assertion
+    ///
+    #[must_use]
+    pub fn with_pacing(console: Console, pacing: Pacing) -> Self {
+        Self { console, pacing }
+    }
+}
+
+impl Reporter for ConsoleReporter {
+    fn suite_started(&mut self, title: &str, description: &str) {
+        self.console.header(title, description);
+    }
+
+    fn group_started(&mut self, title: &str, description: &str, depth: usize) {
+        self.console.title(title, description, depth);
+    }
+
+    fn theory_started(&mut self, title: &str, description: &str, depth: usize) {
+        self.console.sub_title(title, description, depth);
+    }
+
+    fn assertion(&mut self, record: &Record, depth: usize) {
+        self.console.record(record, depth);
+        self.pacing
+            .pause(Duration::from_millis(SUITE_PROGRESS_TIME));
+    }
+
+    fn suite_finished(&mut self, report: &Report) -> std::io::Result<()> {
+        self.console.summary(report);
+        Ok(())
+    }
+}
+
+///
+/// # The reporter writing the assertions of a unit or an assert run at the end
+///
+/// The fancy backend shows them with a progress bar.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct ProgressReporter {
+    console: Console,
+    sleep: u64,
+    strict: bool,
+}
+
+impl ProgressReporter {
+    ///
+    /// # Initialize the reporter
+    ///
+    /// - `console` The backend
+    /// - `sleep` The sleep time between two lines with the animated pacing
+    /// - `strict` The run prohibits the failures
+    ///
+    #[must_use]
+    pub fn new(console: Console, sleep: u64, strict: bool) -> Self {
+        Self {
+            console,
+            sleep,
+            strict,
+        }
+    }
+
+    ///
+    /// # Show the assertions with a progress bar
+    ///
+    /// - `report` The report to show
+    ///
+    fn progress(&self, report: &Report) {
+        let (action, ok, skip) = if self.strict {
+            ("[ ✓ ]", "[ ✓ ]", "[ - ]")
+        } else {
+            ("[ :: ]", "[ OK ]", "[ -- ]")
+        };
+        init_progress_bar_with_eta(report.total());
+        set_progress_bar_action(action, Color::Green, Style::Bold);
+
+        for record in &report.records {
+            pacing::pause(Duration::from_millis(self.sleep));
+            let take: String = format!(
+                "{} {} {}",
+                "take".white().bold(),
+                record.take.to_string().cyan().bold(),
+                "ns".blue().bold()
+            );
+            if record.passed() {
+                print_progress_bar_info(
+                    ok,
+                    format!("{} {take}", record.message.blue().bold()).as_str(),
+                    Color::Green,
+                    Style::Bold,
+                );
+            } else if record.skipped() {
+                print_progress_bar_info(
+                    skip,
+                    record.message.white().bold().to_string().as_str(),
+                    Color::Yellow,
+                    Style::Bold,
+                );
+            } else if record.todo() {
+                print_progress_bar_info(
+                    "[ ~~ ]",
+                    format!("{} {take}", record.message.white().bold()).as_str(),
+                    Color::Cyan,
+                    Style::Bold,
+                );
+            } else {
+                let mut info: String = format!("{} {take}", record.message.purple().bold());
+                if let Some(at) = record.at() {
+                    info.push_str(&format!(" {} {}", "at".white().bold(), at.cyan()));
+                }
+                if let Some(diff) = record.diff() {
+                    info.push('\n');
+                    info.push_str(&diff);
+                }
+                print_progress_bar_info("[ KO ]", info.as_str(), Color::Red, Style::Bold);
+            }
+            inc_progress_bar();
+        }
+
+        let summary: String = if self.strict {
+            strict_totals(report)
+        } else {
+            totals(report)
+        };
+        print_progress_bar_final_info(ok, summary.as_str(), Color::Green, Style::Bold);
+        finalize_progress_bar();
+    }
+}
+
+impl Reporter for ProgressReporter {
+    fn suite_started(&mut self, title: &str, description: &str) {
+        if self.console.ne(&Console::Fancy) {
+            return self.console.header(title, description);
+        }
+        println!("\n{}\n", description.white().bold());
+        if self.strict {
+            println!(
+                "     {}",
+                format_args!("{} {}", "[ + ]".green().bold(), title.blue().bold())
+            );
+        } else {
+            println!(
+                "      {}",
+                format_args!("{} {}", "[ OK ]".green().bold(), title.blue().bold())
+            );
+        }
+    }
+
+    fn suite_finished(&mut self, report: &Report) -> std::io::Result<()> {
+        match self.console {
+            Console::Fancy => self.progress(report),
+            console => console.report(report),
+        }
+        Ok(())
+    }
+}
+
+///
+/// # Format the coloured totals of a report
+///
+/// - `report` The report
+///
+fn totals(report: &Report) -> String {
+    format!(
+        "{} {} {} {} {} {} {} {}",
+        "Assertions :".blue().bold(),
+        report.assertions().to_string().green().bold(),
+        "Failures :".blue().bold(),
+        report.failures().to_string().red().bold(),
+        "Skipped :".blue().bold(),
+        report.skipped().to_string().yellow().bold(),
+        "Todo :".blue().bold(),
+        report.todo().to_string().cyan().bold(),
+    )
+}
+
+///
+/// # Format the coloured totals of a report prohibiting the failures
+///
+/// - `report` The report
+///
+fn strict_totals(report: &Report) -> String {
+    format!(
+        "{} {} {} {} {} {}",
+        report.assertions().to_string().blue().bold(),
+        "assertions".blue().bold(),
+        report.skipped().to_string().yellow().bold(),
+        "skipped".blue().bold(),
+        report.todo().to_string().cyan().bold(),
+        "todo".blue().bold()
+    )
+}
+
+///
+/// # Get the indentation of a nesting depth
+///
+/// The groups of the run and their assertions are not indented.
+///
+/// - `depth` The nesting depth
+///
+#[must_use]
+pub fn indent(depth: usize) -> String {
+    "    ".repeat(depth.saturating_sub(1))
+}
+
+///
+/// # Print a text indented by a nesting depth
+///
+/// - `depth` The nesting depth
+/// - `text` The text
+///
+fn print(depth: usize, text: &str) {
+    let indent: String = indent(depth);
+    for line in text.split('\n') {
+        if line.is_empty() {
+            println!();
+        } else {
+            println!("{indent}{line}");
+        }
+    }
+}
+
+///
+/// # Remove the ANSI escape codes of a text
+///
+/// - `text` The coloured text
+///
+#[must_use]
+pub fn uncoloured(text: &str) -> String {
+    static ANSI: OnceLock<Option<Regex>> = OnceLock::new();
+    ANSI.get_or_init(|| Regex::new("\x1b\\[[0-9;]*m").ok())
+        .as_ref()
+        .map_or_else(
+            || text.to_string(),
+            |ansi| ansi.replace_all(text, "").to_string(),
+        )
+}
+
+///
+/// # Set the backend of all the runners
+///
+/// - `console` The backend
+///
+pub fn set(console: Console) {
+    if let Ok(mut current) = CURRENT.write() {
+        *current = Some(console);
+    }
+}
+
+///
+/// # Get the backend set by `set`, or read from the environment
+///
+#[must_use]
+pub fn current() -> Console {
+    CURRENT
+        .read()
+        .ok()
+        .and_then(|current| *current)
+        .unwrap_or_else(Console::from_env)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::console::{indent, strict_totals, totals, uncoloured, Console, ConsoleReporter};
+    use crate::pacing::Pacing;
+    use crate::report::{Outcome, Record, Report};
+    use crate::reporter::Reporter;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn backends() {
+        assert_eq!(Console::parse("fancy"), Some(Console::Fancy));
+        assert_eq!(Console::parse("plain"), Some(Console::Plain));
+        assert_eq!(Console::parse("quiet"), Some(Console::Quiet));
+        assert_eq!(Console::parse("loud"), None);
+        assert_eq!(uncoloured("\x1b[1;31m- a\x1b[0m"), "- a");
+        assert_eq!(uncoloured("+ b"), "+ b");
+        assert_eq!(
+            uncoloured(&totals(&Report::new("console", ""))),
+            "Assertions : 0 Failures : 0 Skipped : 0 Todo : 0"
+        );
+        let mut todo = Report::new("console", "");
+        todo.records
+            .push(Record::new("ok", "todo", Outcome::Todo, 0, "console"));
+        assert!(uncoloured(&strict_totals(&todo)).ends_with(" 1 todo"));
+        assert_eq!(indent(0), indent(1));
+        assert_eq!(indent(3), " ".repeat(8));
+    }
+
+    #[test]
+    fn pacing() {
+        let record = Record::new("ok", "passed", Outcome::Passed, 0, "console");
+        let mut fixed =
+            ConsoleReporter::with_pacing(Console::Quiet, Pacing::Fixed(Duration::from_millis(30)));
+        let start = Instant::now();
+        fixed.assertion(&record, 1);
+        assert!(start.elapsed() >= Duration::from_millis(30));
+        let mut none = ConsoleReporter::with_pacing(Console::Quiet, Pacing::None);
+        let start = Instant::now();
+        none.assertion(&record, 1);
+        assert!(start.elapsed() < Duration::from_millis(30));
+    }
+}
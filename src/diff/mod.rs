@@ -0,0 +1,166 @@
+use colored_truecolor::Colorize;
+use std::mem::discriminant;
+
+///
+/// # The maximum number of compared token pairs before giving up the diff
+///
+const DIFF_LIMIT: usize = 1_000_000;
+
+///
+/// # A diff change
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+impl<'a> Change<'a> {
+    ///
+    /// # Get the changed token
+    ///
+    #[must_use]
+    pub fn value(&self) -> &'a str {
+        match self {
+            Self::Same(v) | Self::Removed(v) | Self::Added(v) => v,
+        }
+    }
+}
+
+///
+/// # Compute the changes between two token lists with the longest common subsequence
+///
+/// - `expected` The expected tokens
+/// - `actual` The actual tokens
+///
+#[must_use]
+pub fn changes<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<Change<'a>> {
+    let (n, m) = (expected.len(), actual.len());
+    if n.saturating_mul(m) > DIFF_LIMIT {
+        let mut all: Vec<Change> = expected.iter().map(|e| Change::Removed(e)).collect();
+        all.extend(actual.iter().map(|a| Change::Added(a)));
+        return all;
+    }
+    let mut lcs: Vec<Vec<usize>> = vec![vec![0; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut all: Vec<Change> = Vec::new();
+    while i < n && j < m {
+        if expected[i] == actual[j] {
+            all.push(Change::Same(expected[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            all.push(Change::Removed(expected[i]));
+            i += 1;
+        } else {
+            all.push(Change::Added(actual[j]));
+            j += 1;
+        }
+    }
+    all.extend(expected[i..].iter().map(|e| Change::Removed(e)));
+    all.extend(actual[j..].iter().map(|a| Change::Added(a)));
+    all
+}
+
+///
+/// # Split a value in characters
+///
+/// - `value` The value to split
+///
+fn characters(value: &str) -> Vec<&str> {
+    value
+        .char_indices()
+        .map(|(i, c)| &value[i..i + c.len_utf8()])
+        .collect()
+}
+
+///
+/// # Build a coloured line diff
+///
+/// - `expected` The expected value
+/// - `actual` The actual value
+///
+#[must_use]
+pub fn lines(expected: &str, actual: &str) -> String {
+    let e: Vec<&str> = expected.lines().collect();
+    let a: Vec<&str> = actual.lines().collect();
+    changes(&e, &a)
+        .iter()
+        .map(|change| match change {
+            Change::Same(l) => format!("  {l}").white().to_string(),
+            Change::Removed(l) => format!("- {l}").red().bold().to_string(),
+            Change::Added(l) => format!("+ {l}").green().bold().to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+///
+/// # Build a coloured character diff
+///
+/// - `expected` The expected value
+/// - `actual` The actual value
+///
+#[must_use]
+pub fn chars(expected: &str, actual: &str) -> String {
+    let e: Vec<&str> = characters(expected);
+    let a: Vec<&str> = characters(actual);
+    let mut removed: String = "- ".red().bold().to_string();
+    let mut added: String = "+ ".green().bold().to_string();
+    for run in changes(&e, &a).chunk_by(|x, y| discriminant(x) == discriminant(y)) {
+        let text: String = run.iter().map(Change::value).collect();
+        match run[0] {
+            Change::Same(_) => {
+                removed.push_str(&text.red().to_string());
+                added.push_str(&text.green().to_string());
+            }
+            Change::Removed(_) => removed.push_str(&text.red().bold().underline().to_string()),
+            Change::Added(_) => added.push_str(&text.green().bold().underline().to_string()),
+        }
+    }
+    format!("{removed}\n{added}")
+}
+
+///
+/// # Build a coloured diff, by line for multi-line values, by character otherwise
+///
+/// - `expected` The expected value
+/// - `actual` The actual value
+///
+#[must_use]
+pub fn diff(expected: &str, actual: &str) -> String {
+    if expected.contains('\n') || actual.contains('\n') {
+        lines(expected, actual)
+    } else {
+        chars(expected, actual)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::diff::{changes, Change};
+
+    #[test]
+    fn lcs() {
+        assert_eq!(
+            changes(&["a", "b", "c"], &["a", "x", "c", "d"]),
+            vec![
+                Change::Same("a"),
+                Change::Removed("b"),
+                Change::Added("x"),
+                Change::Same("c"),
+                Change::Added("d"),
+            ]
+        );
+    }
+}
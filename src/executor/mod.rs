@@ -0,0 +1,232 @@
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::output::IS_TIMEOUT;
+
+///
+/// # An async hook
+///
+pub type AsyncHook = fn() -> Pin<Box<dyn Future<Output = ()>>>;
+
+///
+/// # Build the failure message of a timed out callback
+///
+/// - `timeout` The exceeded timeout
+///
+#[must_use]
+pub fn timed_out(timeout: Duration) -> String {
+    format!("{IS_TIMEOUT} {} ms", timeout.as_millis())
+}
+
+///
+/// # Run a callback on a worker thread until the timeout is reached
+///
+/// Return `None` if the timeout is reached before the callback completion,
+/// the worker thread is then detached. A panic of the callback is returned as an error.
+///
+/// - `timeout` The maximum duration, unlimited if `None`
+/// - `callback` The callback to run
+///
+pub fn within<T: Send + 'static>(
+    timeout: Option<Duration>,
+    callback: impl FnOnce() -> T + Send + 'static,
+) -> Option<thread::Result<T>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(callback)));
+    });
+    match timeout {
+        Some(t) => receiver.recv_timeout(t).ok(),
+        None => receiver.recv().ok(),
+    }
+}
+
+///
+/// # Run an optional async hook to completion
+///
+/// - `hook` The hook to run
+///
+pub fn hook(hook: Option<AsyncHook>) {
+    if let Some(h) = hook {
+        block_on(h());
+    }
+}
+
+///
+/// # Drive a future to completion
+///
+/// - `future` The future to run
+///
+/// # Panics
+///
+/// With the `tokio` feature, if the runtime can't be built
+///
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    block_on_timeout(future, None).expect("a future without timeout can't time out")
+}
+
+///
+/// # Drive a future to completion or until the timeout is reached
+///
+/// Return `None` if the timeout is reached before the future completion.
+///
+/// The future is driven by a built-in executor parking the current thread.
+///
+/// - `future` The future to run
+/// - `timeout` The maximum duration
+///
+#[cfg(not(feature = "tokio"))]
+pub fn block_on_timeout<F: Future>(future: F, timeout: Option<Duration>) -> Option<F::Output> {
+    park_on(future, timeout)
+}
+
+///
+/// # Drive a future to completion or until the timeout is reached
+///
+/// Return `None` if the timeout is reached before the future completion.
+///
+/// Outside of a tokio runtime, the future is driven by a new current thread runtime.
+/// Inside a runtime, it's driven by the built-in executor parking the current thread:
+/// the other workers of a multi thread runtime drive its timers and its io,
+/// a current thread runtime can't, use a multi thread runtime for them.
+///
+/// - `future` The future to run
+/// - `timeout` The maximum duration
+///
+/// # Panics
+///
+/// If the runtime can't be built
+///
+#[cfg(feature = "tokio")]
+pub fn block_on_timeout<F: Future>(future: F, timeout: Option<Duration>) -> Option<F::Output> {
+    use tokio::runtime::{Builder, Handle, RuntimeFlavor};
+
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor().eq(&RuntimeFlavor::MultiThread) => {
+            tokio::task::block_in_place(|| park_on(future, timeout))
+        }
+        Ok(_) => park_on(future, timeout),
+        Err(_) => {
+            let runtime = Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to build the tokio runtime");
+            match timeout {
+                Some(t) => runtime.block_on(async { tokio::time::timeout(t, future).await.ok() }),
+                None => Some(runtime.block_on(future)),
+            }
+        }
+    }
+}
+
+///
+/// # Drive a future by parking the current thread
+///
+/// Return `None` if the timeout is reached before the future completion.
+///
+/// - `future` The future to run
+/// - `timeout` The maximum duration
+///
+fn park_on<F: Future>(future: F, timeout: Option<Duration>) -> Option<F::Output> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+    use std::time::Instant;
+
+    struct Signal {
+        thread: Thread,
+        woken: AtomicBool,
+    }
+
+    impl Wake for Signal {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.woken.store(true, Ordering::Release);
+            self.thread.unpark();
+        }
+    }
+
+    let deadline: Option<Instant> = timeout.map(|t| Instant::now() + t);
+    let signal: Arc<Signal> = Arc::new(Signal {
+        thread: thread::current(),
+        woken: AtomicBool::new(false),
+    });
+    let waker: Waker = Waker::from(Arc::clone(&signal));
+    let mut context: Context = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return Some(output);
+        }
+        while !signal.woken.swap(false, Ordering::Acquire) {
+            match deadline {
+                Some(d) => {
+                    let now: Instant = Instant::now();
+                    if now >= d {
+                        return None;
+                    }
+                    thread::park_timeout(d - now);
+                }
+                None => thread::park(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::executor::{block_on, block_on_timeout, within};
+    use std::future::{pending, ready};
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    fn executor() {
+        assert_eq!(block_on(async { ready(2).await * 2 }), 4);
+        assert_eq!(
+            block_on_timeout(pending::<()>(), Some(Duration::from_millis(10))),
+            None
+        );
+        assert_eq!(within(None, || 2).map(Result::ok), Some(Some(2)));
+        assert!(within(Some(Duration::from_millis(10)), || sleep(
+            Duration::from_secs(1)
+        ))
+        .is_none());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn runtimes() {
+        let sleeping = || async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            2
+        };
+        let multi = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build the tokio runtime");
+        assert_eq!(
+            multi.block_on(async { block_on_timeout(sleeping(), Some(Duration::from_secs(1))) }),
+            Some(2)
+        );
+        let current = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build the tokio runtime");
+        assert_eq!(current.block_on(async { block_on(ready(2)) }), 2);
+        assert_eq!(
+            current.block_on(async {
+                block_on_timeout(pending::<()>(), Some(Duration::from_millis(10)))
+            }),
+            None
+        );
+    }
+}
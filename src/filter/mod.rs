@@ -0,0 +1,283 @@
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+///
+/// # The environment variable containing the filter
+///
+/// The `--filter <filter>` or `--filter=<filter>` command line arguments are read when it's not defined,
+/// for test binaries without the default harness.
+///
+pub const FILTER: &str = "UNIT_TESTING_FILTER";
+
+///
+/// # The command line argument containing the filter
+///
+const FILTER_ARGUMENT: &str = "--filter";
+
+///
+/// # A title pattern
+///
+#[derive(Clone, Debug)]
+pub enum Pattern {
+    /// The title contains the value
+    Substring(String),
+    /// The title is the value
+    Exact(String),
+    /// The title matches the regex
+    Regex(Regex),
+}
+
+impl Pattern {
+    ///
+    /// # Check if a title matches the pattern
+    ///
+    /// - `title` The title to check
+    ///
+    #[must_use]
+    pub fn matches(&self, title: &str) -> bool {
+        match self {
+            Self::Substring(value) => title.contains(value.as_str()),
+            Self::Exact(value) => title.eq(value),
+            Self::Regex(regex) => regex.is_match(title),
+        }
+    }
+}
+
+///
+/// # Split a filter on the commas outside of braces and brackets
+///
+/// - `filter` The comma separated terms
+///
+fn split(filter: &str) -> Vec<&str> {
+    let mut terms: Vec<&str> = Vec::new();
+    let mut depth: usize = 0;
+    let mut start: usize = 0;
+    for (i, c) in filter.char_indices() {
+        match c {
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth.eq(&0) => {
+                terms.push(&filter[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    terms.push(&filter[start..]);
+    terms
+}
+
+///
+/// # Build a regex pattern, or a substring pattern when the regex is invalid
+///
+/// An invalid regex is reported once on the standard error.
+///
+/// - `regex` The regex
+///
+fn regex(regex: &str) -> Pattern {
+    static INVALID: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+    match Regex::new(regex) {
+        Ok(valid) => Pattern::Regex(valid),
+        Err(e) => {
+            let first: bool = INVALID
+                .get_or_init(Mutex::default)
+                .lock()
+                .map(|mut invalid| invalid.insert(regex.to_string()))
+                .unwrap_or(true);
+            if first {
+                eprintln!(
+                    "The filter /{regex}/ is not a valid regex, it's matched as a substring: {e}"
+                );
+            }
+            Pattern::Substring(regex.to_string())
+        }
+    }
+}
+
+///
+/// # A filter selecting the groups and the `it` blocks to run by title
+///
+/// The filter is a comma separated list of terms, the commas inside braces and brackets are kept:
+///
+/// | Term         | Match                             |
+/// |--------------|-----------------------------------|
+/// | `value`      | The title contains `value`        |
+/// | `=value`     | The title is `value`              |
+/// | `/regex/`    | The title matches `regex`         |
+/// | `@tag`       | The item is tagged with `tag`     |
+/// | `!term`      | Exclude the items matching `term` |
+///
+/// An item is selected when its title or a tag matches an inclusive term, or when there is none,
+/// and when they match no exclusion. An invalid regex is matched as a substring.
+///
+#[derive(Clone, Debug, Default)]
+pub struct Filter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+    include_tags: Vec<String>,
+    exclude_tags: Vec<String>,
+}
+
+impl Filter {
+    ///
+    /// # Parse a filter
+    ///
+    /// - `filter` The comma separated terms
+    ///
+    #[must_use]
+    pub fn parse(filter: &str) -> Self {
+        let mut all = Self::default();
+        for term in split(filter)
+            .into_iter()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+        {
+            let (exclude, term) = term.strip_prefix('!').map_or((false, term), |t| (true, t));
+            if let Some(tag) = term.strip_prefix('@') {
+                if exclude {
+                    all.exclude_tags.push(tag.to_string());
+                } else {
+                    all.include_tags.push(tag.to_string());
+                }
+                continue;
+            }
+            let pattern: Pattern = if let Some(exact) = term.strip_prefix('=') {
+                Pattern::Exact(exact.to_string())
+            } else if term.len() > 1 && term.starts_with('/') && term.ends_with('/') {
+                regex(&term[1..term.len() - 1])
+            } else {
+                Pattern::Substring(term.to_string())
+            };
+            if exclude {
+                all.exclude.push(pattern);
+            } else {
+                all.include.push(pattern);
+            }
+        }
+        all
+    }
+
+    ///
+    /// # Read the filter from the `UNIT_TESTING_FILTER` environment variable or the command line
+    ///
+    #[must_use]
+    pub fn from_env() -> Self {
+        if let Ok(filter) = std::env::var(FILTER) {
+            return Self::parse(&filter);
+        }
+        let args: Vec<String> = std::env::args().collect();
+        let mut filters: Vec<String> = Vec::new();
+        for (i, arg) in args.iter().enumerate() {
+            if let Some(filter) = arg.strip_prefix(&format!("{FILTER_ARGUMENT}=")) {
+                filters.push(filter.to_string());
+            } else if arg.eq(FILTER_ARGUMENT) {
+                if let Some(filter) = args.get(i + 1) {
+                    filters.push(filter.to_string());
+                }
+            }
+        }
+        Self::parse(&filters.join(","))
+    }
+
+    ///
+    /// # Check if the filter has inclusive terms
+    ///
+    #[must_use]
+    pub fn is_restrictive(&self) -> bool {
+        !self.include.is_empty() || !self.include_tags.is_empty()
+    }
+
+    ///
+    /// # Check if a title matches an inclusive term
+    ///
+    /// - `title` The title to check
+    ///
+    #[must_use]
+    pub fn includes(&self, title: &str) -> bool {
+        self.include.iter().any(|p| p.matches(title))
+    }
+
+    ///
+    /// # Check if a title matches an exclusion
+    ///
+    /// - `title` The title to check
+    ///
+    #[must_use]
+    pub fn excludes(&self, title: &str) -> bool {
+        self.exclude.iter().any(|p| p.matches(title))
+    }
+
+    ///
+    /// # Check if a tag matches an inclusive term
+    ///
+    /// - `tags` The tags to check
+    ///
+    #[must_use]
+    pub fn includes_tags(&self, tags: &[&str]) -> bool {
+        tags.iter()
+            .any(|tag| self.include_tags.iter().any(|t| t.eq(tag)))
+    }
+
+    ///
+    /// # Check if a tag matches an exclusion
+    ///
+    /// - `tags` The tags to check
+    ///
+    #[must_use]
+    pub fn excludes_tags(&self, tags: &[&str]) -> bool {
+        tags.iter()
+            .any(|tag| self.exclude_tags.iter().any(|t| t.eq(tag)))
+    }
+
+    ///
+    /// # Check if a title is selected
+    ///
+    /// - `title` The title to check
+    ///
+    #[must_use]
+    pub fn selects(&self, title: &str) -> bool {
+        self.selects_tagged(title, &[])
+    }
+
+    ///
+    /// # Check if a tagged item is selected
+    ///
+    /// - `title` The title to check
+    /// - `tags` The tags to check
+    ///
+    #[must_use]
+    pub fn selects_tagged(&self, title: &str, tags: &[&str]) -> bool {
+        (!self.is_restrictive() || self.includes(title) || self.includes_tags(tags))
+            && !self.excludes(title)
+            && !self.excludes_tags(tags)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::Filter;
+
+    #[test]
+    fn terms() {
+        let filter = Filter::parse("equal, =Should panic, /^Check .* valid$/, !slow");
+        assert!(filter.selects("Should be equals"));
+        assert!(filter.selects("Should panic"));
+        assert!(!filter.selects("Should panic twice"));
+        assert!(filter.selects("Check is theorem are valid"));
+        assert!(!filter.selects("Should be equals, slow"));
+        assert!(!filter.selects("Should be contains"));
+        assert!(Filter::parse("").selects("anything"));
+        assert!(!Filter::parse("!any").selects("anything"));
+        let tags = Filter::parse("@net, !@slow");
+        assert!(tags.selects_tagged("Should fetch", &["net"]));
+        assert!(!tags.selects_tagged("Should fetch", &["net", "slow"]));
+        assert!(!tags.selects("Should fetch"));
+        let repeated = Filter::parse("/^a{1,3}$/, b");
+        assert!(repeated.selects("aaa") && repeated.selects("b"));
+        assert!(!repeated.selects("aaaa"));
+        let invalid = Filter::parse("/(unclosed/");
+        assert!(invalid.selects("Check (unclosed group"));
+        assert!(!invalid.selects("Check closed group"));
+    }
+}
@@ -0,0 +1,355 @@
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+
+use crate::report::{Outcome, Record, Report};
+use crate::reporter::Reporter;
+
+///
+/// # The environment variable containing the file receiving the events
+///
+/// The events are written on the standard output when it's not defined.
+///
+pub const JSON_FILE: &str = "UNIT_TESTING_JSON";
+
+///
+/// # The event schema version, the crate version
+///
+pub const SCHEMA: &str = env!("CARGO_PKG_VERSION");
+
+///
+/// # An event of the json stream
+///
+/// Each event is written on one line as a json object with a `schema` and an `event` key.
+///
+/// | `event`            | Keys                                                                                      |
+/// |--------------------|-------------------------------------------------------------------------------------------|
+/// | `suite_started`    | `title`, `description`                                                                    |
+/// | `group_started`    | `title`, `description`, `depth`                                                           |
+/// | `group_finished`   | `title`, `depth`                                                                          |
+/// | `assertion_passed` | `kind`, `message`, `take`, `group`, `expected`, `actual`, `location` (`file`, `line`, `column`) |
+/// | `assertion_failed` | same as `assertion_passed`                                                                |
+/// | `assertion_skipped`| same as `assertion_passed`                                                                |
+/// | `assertion_todo`   | same as `assertion_passed`                                                                |
+/// | `hook_executed`    | `name`                                                                                    |
+/// | `suite_finished`   | `title`, `assertions`, `failures`, `skipped`, `todo`, `take`                              |
+///
+/// `take` is in nanoseconds, `expected`, `actual` and `location` are `null` when unknown.
+///
+#[derive(Clone, Copy, Debug)]
+pub enum Event<'a> {
+    SuiteStarted {
+        title: &'a str,
+        description: &'a str,
+    },
+    GroupStarted {
+        title: &'a str,
+        description: &'a str,
+        depth: usize,
+    },
+    GroupFinished {
+        title: &'a str,
+        depth: usize,
+    },
+    Assertion(&'a Record),
+    HookExecuted {
+        name: &'a str,
+    },
+    SuiteFinished {
+        title: &'a str,
+        assertions: usize,
+        failures: usize,
+        skipped: usize,
+        todo: usize,
+        take: u128,
+    },
+}
+
+///
+/// # Quote a json string
+///
+/// - `value` The value to quote
+///
+fn quote(value: &str) -> String {
+    let mut json = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+///
+/// # Quote an optional json string
+///
+/// - `value` The value to quote
+///
+fn nullable(value: Option<&String>) -> String {
+    value.map_or_else(|| String::from("null"), |v| quote(v))
+}
+
+impl Event<'_> {
+    ///
+    /// # Get the event name
+    ///
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SuiteStarted { .. } => "suite_started",
+            Self::GroupStarted { .. } => "group_started",
+            Self::GroupFinished { .. } => "group_finished",
+            Self::Assertion(record) => match record.outcome {
+                Outcome::Passed => "assertion_passed",
+                Outcome::Failed => "assertion_failed",
+                Outcome::Skipped => "assertion_skipped",
+                Outcome::Todo => "assertion_todo",
+            },
+            Self::HookExecuted { .. } => "hook_executed",
+            Self::SuiteFinished { .. } => "suite_finished",
+        }
+    }
+
+    ///
+    /// # Serialize the event on one line
+    ///
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut json = format!(
+            "{{\"schema\":{},\"event\":{}",
+            quote(SCHEMA),
+            quote(self.name())
+        );
+        match self {
+            Self::SuiteStarted { title, description } => {
+                let _ = write!(
+                    json,
+                    ",\"title\":{},\"description\":{}",
+                    quote(title),
+                    quote(description)
+                );
+            }
+            Self::GroupStarted {
+                title,
+                description,
+                depth,
+            } => {
+                let _ = write!(
+                    json,
+                    ",\"title\":{},\"description\":{},\"depth\":{depth}",
+                    quote(title),
+                    quote(description)
+                );
+            }
+            Self::GroupFinished { title, depth } => {
+                let _ = write!(json, ",\"title\":{},\"depth\":{depth}", quote(title));
+            }
+            Self::Assertion(record) => {
+                let location = record.location.map_or_else(
+                    || String::from("null"),
+                    |l| {
+                        format!(
+                            "{{\"file\":{},\"line\":{},\"column\":{}}}",
+                            quote(l.file()),
+                            l.line(),
+                            l.column()
+                        )
+                    },
+                );
+                let _ = write!(
+                    json,
+                    ",\"kind\":{},\"message\":{},\"take\":{},\"group\":{},\"expected\":{},\"actual\":{},\"location\":{location}",
+                    quote(&record.kind),
+                    quote(&record.message),
+                    record.take,
+                    quote(&record.group),
+                    nullable(record.expected.as_ref()),
+                    nullable(record.actual.as_ref()),
+                );
+            }
+            Self::HookExecuted { name } => {
+                let _ = write!(json, ",\"name\":{}", quote(name));
+            }
+            Self::SuiteFinished {
+                title,
+                assertions,
+                failures,
+                skipped,
+                todo,
+                take,
+            } => {
+                let _ = write!(
+                    json,
+                    ",\"title\":{},\"assertions\":{assertions},\"failures\":{failures},\"skipped\":{skipped},\"todo\":{todo},\"take\":{take}",
+                    quote(title)
+                );
+            }
+        }
+        json.push('}');
+        json
+    }
+}
+
+///
+/// # Write an event on the standard output or in the `UNIT_TESTING_JSON` file
+///
+/// - `event` The event to write
+///
+/// # Errors
+///
+/// On write failure
+///
+pub fn emit(event: &Event) -> io::Result<()> {
+    emit_to(event, std::env::var(JSON_FILE).ok().as_deref())
+}
+
+///
+/// # Write an event on the standard output or in a file
+///
+/// - `event` The event to write
+/// - `file` The file receiving the events, the standard output if `None` or empty
+///
+/// # Errors
+///
+/// On open or write failure
+///
+pub fn emit_to(event: &Event, file: Option<&str>) -> io::Result<()> {
+    let line = format!("{}\n", event.to_json());
+    match file {
+        Some(path) if !path.is_empty() => OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?
+            .write_all(line.as_bytes()),
+        _ => io::stdout().lock().write_all(line.as_bytes()),
+    }
+}
+
+///
+/// # The reporter writing the events as json lines
+///
+/// The first write failure is returned when the run is finished.
+///
+#[derive(Debug, Default)]
+pub struct JsonReporter {
+    file: Option<String>,
+    error: Option<io::Error>,
+}
+
+impl JsonReporter {
+    ///
+    /// # Initialize the reporter writing in a file
+    ///
+    /// - `file` The file receiving the events
+    ///
+    #[must_use]
+    pub fn new(file: &str) -> Self {
+        Self {
+            file: Some(file.to_string()),
+            error: None,
+        }
+    }
+
+    ///
+    /// # Write an event
+    ///
+    /// - `event` The event to write
+    ///
+    fn write(&mut self, event: &Event) {
+        let written: io::Result<()> = match &self.file {
+            Some(file) => emit_to(event, Some(file)),
+            None => emit(event),
+        };
+        if let Err(e) = written {
+            self.error.get_or_insert(e);
+        }
+    }
+}
+
+impl Reporter for JsonReporter {
+    fn suite_started(&mut self, title: &str, description: &str) {
+        self.write(&Event::SuiteStarted { title, description });
+    }
+
+    fn group_started(&mut self, title: &str, description: &str, depth: usize) {
+        self.write(&Event::GroupStarted {
+            title,
+            description,
+            depth,
+        });
+    }
+
+    fn assertion(&mut self, record: &Record, _depth: usize) {
+        self.write(&Event::Assertion(record));
+    }
+
+    fn hook_executed(&mut self, name: &str) {
+        self.write(&Event::HookExecuted { name });
+    }
+
+    fn group_finished(&mut self, title: &str, depth: usize) {
+        self.write(&Event::GroupFinished { title, depth });
+    }
+
+    fn suite_finished(&mut self, report: &Report) -> io::Result<()> {
+        self.write(&Event::SuiteFinished {
+            title: &report.title,
+            assertions: report.assertions(),
+            failures: report.failures(),
+            skipped: report.skipped(),
+            todo: report.todo(),
+            take: report.take(),
+        });
+        self.error.take().map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::json::{Event, JsonReporter, SCHEMA};
+    use crate::output::IS_NOT_BEGIN;
+    use crate::report::{Outcome, Record, Report};
+    use crate::reporter::Reporter;
+
+    #[test]
+    fn events() {
+        let record = Record::new("start_with", IS_NOT_BEGIN, Outcome::Failed, 20, "json")
+            .with_values("a\"b", "xyz");
+        assert_eq!(
+            Event::Assertion(&record).to_json(),
+            format!("{{\"schema\":\"{SCHEMA}\",\"event\":\"assertion_failed\",\"kind\":\"start_with\",\"message\":\"{IS_NOT_BEGIN}\",\"take\":20,\"group\":\"json\",\"expected\":\"a\\\"b\",\"actual\":\"xyz\",\"location\":null}}")
+        );
+        assert_eq!(
+            Event::SuiteFinished {
+                title: "json",
+                assertions: 2,
+                failures: 1,
+                skipped: 0,
+                todo: 0,
+                take: 30
+            }
+            .to_json(),
+            format!("{{\"schema\":\"{SCHEMA}\",\"event\":\"suite_finished\",\"title\":\"json\",\"assertions\":2,\"failures\":1,\"skipped\":0,\"todo\":0,\"take\":30}}")
+        );
+    }
+
+    #[test]
+    fn unwritable() {
+        let directory =
+            std::env::temp_dir().join(format!("unit-testing-json-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("failed to create the directory");
+        let mut reporter = JsonReporter::new(&directory.to_string_lossy());
+        reporter.suite_started("json", "A directory can't receive the events");
+        assert!(reporter.suite_finished(&Report::new("json", "")).is_err());
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
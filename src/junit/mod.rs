@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use crate::report::{Record, Report};
+use crate::reporter::Reporter;
+
+///
+/// # The environment variable containing the junit output directory
+///
+pub const JUNIT_DIRECTORY: &str = "UNIT_TESTING_JUNIT";
+
+///
+/// # Escape a value for an xml attribute
+///
+/// - `value` The value to escape
+///
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+///
+/// # Convert nanoseconds to seconds
+///
+/// - `take` The time in nanoseconds
+///
+fn seconds(take: u128) -> String {
+    format!("{:.9}", take as f64 / 1_000_000_000.0)
+}
+
+///
+/// # Build a file name from a report title, unique in the process
+///
+/// The reports sharing a title are numbered from the second one.
+///
+/// - `title` The report title
+///
+fn filename(title: &str) -> String {
+    static NAMES: OnceLock<Mutex<HashMap<String, usize>>> = OnceLock::new();
+    let mut name: String = title
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    if name.is_empty() {
+        name = String::from("unit-testing");
+    }
+    let count: usize = NAMES
+        .get_or_init(Mutex::default)
+        .lock()
+        .map(|mut names| {
+            let count: &mut usize = names.entry(name.clone()).or_default();
+            *count += 1;
+            *count
+        })
+        .unwrap_or(1);
+    if count > 1 {
+        format!("{name}-{count}.xml")
+    } else {
+        format!("{name}.xml")
+    }
+}
+
+///
+/// # Resolve the junit directory
+///
+/// - `directory` The output directory, the `UNIT_TESTING_JUNIT` environment variable if `None`
+///
+fn directory(directory: Option<&str>) -> Option<String> {
+    match directory {
+        Some(d) => Some(d.to_string()),
+        None => std::env::var(JUNIT_DIRECTORY)
+            .ok()
+            .filter(|d| !d.is_empty()),
+    }
+}
+
+///
+/// # Render reports as a junit xml document
+///
+/// - `reports` The reports to render
+///
+#[must_use]
+pub fn render(reports: &[Report]) -> String {
+    let tests: usize = reports.iter().map(Report::total).sum();
+    let failures: usize = reports.iter().map(Report::failures).sum();
+    let skipped: usize = reports.iter().map(|r| r.skipped() + r.todo()).sum();
+    let take: u128 = reports.iter().map(Report::take).sum();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites tests=\"{tests}\" failures=\"{failures}\" skipped=\"{skipped}\" time=\"{}\">",
+        seconds(take)
+    );
+    for report in reports {
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{}\">",
+            escape(&report.title),
+            report.total(),
+            report.failures(),
+            report.skipped() + report.todo(),
+            seconds(report.take())
+        );
+        for record in &report.records {
+            let classname = if record.group.is_empty() {
+                &report.title
+            } else {
+                &record.group
+            };
+            let _ = write!(
+                xml,
+                "    <testcase name=\"{}: {}\" classname=\"{}\" time=\"{}\"",
+                escape(&record.kind),
+                escape(&record.message),
+                escape(classname),
+                seconds(record.take)
+            );
+            if let Some(location) = record.location {
+                let _ = write!(
+                    xml,
+                    " file=\"{}\" line=\"{}\"",
+                    escape(location.file()),
+                    location.line()
+                );
+            }
+            if record.failed() {
+                let _ = write!(
+                    xml,
+                    ">\n      <failure message=\"{}\" type=\"{}\"",
+                    escape(&record.message),
+                    escape(&record.kind)
+                );
+                match (&record.expected, &record.actual) {
+                    (Some(expected), Some(actual)) => {
+                        let _ = write!(
+                            xml,
+                            ">expected: {}\nactual: {}</failure>",
+                            escape(expected),
+                            escape(actual)
+                        );
+                    }
+                    _ => xml.push_str("/>"),
+                }
+                xml.push_str("\n    </testcase>\n");
+            } else if record.skipped() {
+                xml.push_str(">\n      <skipped/>\n    </testcase>\n");
+            } else if record.todo() {
+                xml.push_str(">\n      <skipped message=\"todo\"/>\n    </testcase>\n");
+            } else {
+                xml.push_str("/>\n");
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+///
+/// # Write reports as a junit xml file
+///
+/// - `path` The file to write
+/// - `reports` The reports to write
+///
+/// # Errors
+///
+/// On write failure
+///
+pub fn write(path: &str, reports: &[Report]) -> io::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, render(reports))
+}
+
+///
+/// # Export a report in the junit directory
+///
+/// The directory is the `directory` value if defined, else the `UNIT_TESTING_JUNIT` environment variable.
+/// Nothing is written if none of them are defined.
+///
+/// - `directory` The output directory
+/// - `report` The report to export
+///
+/// # Errors
+///
+/// On write failure
+///
+pub fn export(directory: Option<&str>, report: &Report) -> io::Result<()> {
+    match self::directory(directory) {
+        Some(directory) => {
+            let path = Path::new(directory.as_str()).join(filename(&report.title));
+            write(&path.to_string_lossy(), std::slice::from_ref(report))
+        }
+        None => Ok(()),
+    }
+}
+
+///
+/// # The reporter exporting the report in a junit directory
+///
+/// The file is written on each failure, a run ending with a panic keeps its failures,
+/// and rewritten at the end of the run.
+///
+#[derive(Clone, Debug, Default)]
+pub struct JunitReporter {
+    directory: Option<String>,
+    report: Option<Report>,
+    path: Option<String>,
+}
+
+impl JunitReporter {
+    ///
+    /// # Initialize the reporter
+    ///
+    /// - `directory` The output directory, the `UNIT_TESTING_JUNIT` environment variable if `None`
+    ///
+    #[must_use]
+    pub fn new(directory: Option<&str>) -> Self {
+        Self {
+            directory: directory.map(ToString::to_string),
+            report: None,
+            path: None,
+        }
+    }
+
+    ///
+    /// # Write a report in the file of the run
+    ///
+    /// - `report` The report to write
+    ///
+    fn save(&mut self, report: &Report) -> io::Result<()> {
+        if self.path.is_none() {
+            self.path = directory(self.directory.as_deref()).map(|d| {
+                Path::new(d.as_str())
+                    .join(filename(&report.title))
+                    .to_string_lossy()
+                    .to_string()
+            });
+        }
+        match &self.path {
+            Some(path) => write(path, std::slice::from_ref(report)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Reporter for JunitReporter {
+    fn suite_started(&mut self, title: &str, description: &str) {
+        self.report = Some(Report::new(title, description));
+        self.path = None;
+    }
+
+    fn assertion(&mut self, record: &Record, _depth: usize) {
+        if let Some(mut report) = self.report.take() {
+            report.records.push(record.clone());
+            if record.failed() {
+                let _ = self.save(&report);
+            }
+            self.report = Some(report);
+        }
+    }
+
+    fn suite_finished(&mut self, report: &Report) -> io::Result<()> {
+        match self.report.take() {
+            Some(started) if started.title.eq(&report.title) => {}
+            _ => self.path = None,
+        }
+        self.save(report)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::junit::render;
+    use crate::output::{IS_CONTAINS, IS_NOT_CONTAINS};
+    use crate::report::{Outcome, Record, Report};
+    use crate::suite::describe_with;
+    use std::fs;
+
+    #[test]
+    fn xml() {
+        let mut report = Report::new("junit <report>", "Check the junit output");
+        report.records.push(Record::new(
+            "str_contains",
+            IS_CONTAINS,
+            Outcome::Passed,
+            1_500,
+            "group",
+        ));
+        report.records.push(Record::new(
+            "str_contains",
+            IS_NOT_CONTAINS,
+            Outcome::Failed,
+            2_000_000_000,
+            "group",
+        ));
+        let xml = render(&[report]);
+        assert!(xml.contains(
+            "<testsuites tests=\"2\" failures=\"1\" skipped=\"0\" time=\"2.000001500\">"
+        ));
+        assert!(xml.contains("<testsuite name=\"junit &lt;report&gt;\""));
+        assert!(xml.contains("time=\"0.000001500\"/>"));
+        assert!(xml.contains(
+            "<failure message=\"The value don&apos;t contains the data\" type=\"str_contains\"/>"
+        ));
+    }
+
+    #[test]
+    fn export() {
+        let directory =
+            std::env::temp_dir().join(format!("unit-testing-junit-{}", std::process::id()));
+        let dir = directory.to_string_lossy().to_string();
+        let failing = dir.clone();
+        let failed = std::panic::catch_unwind(move || {
+            describe_with(
+                "Check the junit export",
+                "A failure is exported before the panic",
+                || (),
+                |()| {},
+                move |f| f.then(|s| s.junit(&failing).eq(&1, &2)),
+            )
+        });
+        assert!(failed.is_err());
+        let _ = describe_with(
+            "Check the junit export",
+            "A suite sharing the title has its own file",
+            || (),
+            |()| {},
+            move |f| f.then(|s| s.junit(&dir).eq(&1, &1)),
+        )
+        .end_with_report();
+        let first = fs::read_to_string(directory.join("Check-the-junit-export.xml"));
+        let second = fs::read_to_string(directory.join("Check-the-junit-export-2.xml"));
+        let _ = fs::remove_dir_all(&directory);
+        assert!(first.is_ok_and(|xml| xml.contains("<failure message=")));
+        assert!(second.is_ok_and(|xml| xml.contains("failures=\"0\"")));
+    }
+}
@@ -1,8 +1,28 @@
 #![allow(clippy::multiple_crate_versions)]
+#![cfg_attr(
+    test,
+    allow(unknown_lints, clippy::useless_vec, clippy::manual_is_multiple_of)
+)]
+pub mod approx;
 pub mod assertions;
+pub mod command;
+pub mod console;
+pub mod diff;
+pub mod executor;
+pub mod filter;
+pub mod json;
+pub mod junit;
+pub mod matcher;
 pub mod objects;
 pub mod output;
+pub mod pacing;
+pub mod parallel;
+pub mod property;
+pub mod report;
+pub mod reporter;
+pub mod snapshot;
 pub mod suite;
+pub mod tap;
 pub mod unit;
 
 ///
@@ -23,9 +43,12 @@ macro_rules! assert_that {
 ///
 /// # Failures are not prohibited
 ///
+/// With a failure policy, return the report as a `Result` according to the policy.
+///
 /// - `title` A title
 /// - `description` A detailed description
 /// - `time` The asserting sleep time
+/// - `policy` The optional failure policy
 /// - `callbacks` The callbacks to execute
 ///
 #[macro_export]
@@ -33,6 +56,9 @@ macro_rules! check_that {
     ($title:expr,$description:expr,$time:expr,$callbacks:expr) => {
         Unit::it($title, $description, $time, $callbacks);
     };
+    ($title:expr,$description:expr,$time:expr,$policy:expr,$callbacks:expr) => {
+        Unit::try_it($title, $description, $time, $policy, $callbacks)
+    };
 }
 ///
 /// # Always panic but disable output message
@@ -56,21 +82,28 @@ macro_rules! always_panic {
 /// - `before` The before each callback
 /// - `after` The after each callback
 ///
+/// The test is printed by the console backend, the pause after it is set by the pacing.
+/// The after callback runs before a failure panics.
+///
 #[macro_export]
 macro_rules! run {
     ($t:expr,$s:expr,$e:expr,$before:ident,$after:ident) => {
         if let Some(a) = $before {
             a();
         }
-        if $t.eq(&false) {
-            println!("{}\n", format!("\t\t* {}", $e).as_str());
-            panic!("{}", $e);
+        let test: bool = $t;
+        if test {
+            $crate::console::current().passed($s, 0);
+        } else {
+            $crate::console::current().failed($e, 0);
         }
-        println!("{}\n", format!("\t\t{} {}", "", $s).as_str());
         if let Some(b) = $after {
             b();
         }
-        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(test, "{}", $e);
+        $crate::pacing::pause(std::time::Duration::from_millis(
+            $crate::output::SUITE_PROGRESS_TIME,
+        ));
     };
 }
 
@@ -89,7 +122,7 @@ macro_rules! run {
 #[macro_export]
 macro_rules! it {
     ($title:expr,$description:expr,$before_all:ident,$before:ident,$after_all:ident,$after:ident,$main:ident) => {
-        assert!($crate::suite::describe(
+        if let Err(e) = $crate::suite::describe(
             $title,
             $description,
             $after_all,
@@ -99,6 +132,8 @@ macro_rules! it {
             $main,
         )
         .end()
-        .is_ok());
+        {
+            panic!("{e}");
+        }
     };
 }
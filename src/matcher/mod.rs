@@ -0,0 +1,443 @@
+use regex::Regex;
+use std::fmt::Debug;
+use std::path::Path;
+
+///
+/// # Check a value and explain why it doesn't match
+///
+pub trait Matcher<T: ?Sized> {
+    ///
+    /// # Check if a value matches
+    ///
+    /// - `actual` The actual value
+    ///
+    fn matches(&self, actual: &T) -> bool;
+
+    ///
+    /// # Describe the expected value
+    ///
+    fn describe(&self) -> String;
+
+    ///
+    /// # Describe why a value doesn't match
+    ///
+    /// - `actual` The actual value
+    ///
+    fn describe_mismatch(&self, actual: &T) -> String {
+        let _ = actual;
+        format!("was not {}", self.describe())
+    }
+}
+
+impl<T: ?Sized> Matcher<T> for Box<dyn Matcher<T>> {
+    fn matches(&self, actual: &T) -> bool {
+        self.as_ref().matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        self.as_ref().describe()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        self.as_ref().describe_mismatch(actual)
+    }
+}
+
+///
+/// # Explain why a value doesn't match
+///
+type Mismatch<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
+///
+/// # A matcher built from a predicate
+///
+pub struct Is<'a, T: ?Sized> {
+    description: String,
+    test: Box<dyn Fn(&T) -> bool + 'a>,
+    mismatch: Option<Mismatch<'a, T>>,
+}
+
+impl<T: ?Sized> Is<'_, T> {
+    ///
+    /// # Show the actual value on mismatch
+    ///
+    #[must_use]
+    pub fn showing(mut self) -> Self
+    where
+        T: Debug,
+    {
+        self.mismatch = Some(Box::new(|actual: &T| format!("was {actual:?}")));
+        self
+    }
+}
+
+impl<T: ?Sized> Matcher<T> for Is<'_, T> {
+    fn matches(&self, actual: &T) -> bool {
+        (self.test)(actual)
+    }
+
+    fn describe(&self) -> String {
+        self.description.clone()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        self.mismatch.as_ref().map_or_else(
+            || format!("was not {}", self.description),
+            |mismatch| mismatch(actual),
+        )
+    }
+}
+
+///
+/// # A matcher requiring all its matchers
+///
+pub struct AllOf<T: ?Sized> {
+    matchers: Vec<Box<dyn Matcher<T>>>,
+}
+
+impl<T: ?Sized> Matcher<T> for AllOf<T> {
+    fn matches(&self, actual: &T) -> bool {
+        self.matchers.iter().all(|m| m.matches(actual))
+    }
+
+    fn describe(&self) -> String {
+        join(&self.matchers, " and ")
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        self.matchers
+            .iter()
+            .find(|m| !m.matches(actual))
+            .map(|m| m.describe_mismatch(actual))
+            .unwrap_or_default()
+    }
+}
+
+///
+/// # A matcher requiring one of its matchers
+///
+pub struct AnyOf<T: ?Sized> {
+    matchers: Vec<Box<dyn Matcher<T>>>,
+}
+
+impl<T: ?Sized> Matcher<T> for AnyOf<T> {
+    fn matches(&self, actual: &T) -> bool {
+        self.matchers.iter().any(|m| m.matches(actual))
+    }
+
+    fn describe(&self) -> String {
+        join(&self.matchers, " or ")
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        self.matchers
+            .iter()
+            .map(|m| m.describe_mismatch(actual))
+            .collect::<Vec<String>>()
+            .join(" and ")
+    }
+}
+
+///
+/// # A matcher inverting its matcher
+///
+pub struct Not<M> {
+    matcher: M,
+}
+
+impl<T: ?Sized, M: Matcher<T>> Matcher<T> for Not<M> {
+    fn matches(&self, actual: &T) -> bool {
+        !self.matcher.matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        format!("not {}", self.matcher.describe())
+    }
+
+    fn describe_mismatch(&self, _actual: &T) -> String {
+        format!("was {}", self.matcher.describe())
+    }
+}
+
+///
+/// # Join the descriptions of matchers
+///
+/// - `matchers` The matchers
+/// - `separator` The separator
+///
+fn join<T: ?Sized>(matchers: &[Box<dyn Matcher<T>>], separator: &str) -> String {
+    matchers
+        .iter()
+        .map(|m| m.describe())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+///
+/// # Check a value with a matcher
+///
+/// Return the result, the success message and the error message.
+///
+/// - `actual` The actual value
+/// - `matcher` The matcher
+///
+pub fn check<T: ?Sized, M: Matcher<T>>(actual: &T, matcher: &M) -> (bool, String, String) {
+    let description: String = matcher.describe();
+    if matcher.matches(actual) {
+        (true, description, String::new())
+    } else {
+        let mismatch: String = matcher.describe_mismatch(actual);
+        (
+            false,
+            description.clone(),
+            format!("{description}, {mismatch}"),
+        )
+    }
+}
+
+///
+/// # Build a matcher from a predicate
+///
+/// - `description` The description of the expected value
+/// - `test` The predicate
+///
+pub fn is<'a, T: ?Sized>(description: &str, test: impl Fn(&T) -> bool + 'a) -> Is<'a, T> {
+    Is {
+        description: description.to_string(),
+        test: Box::new(test),
+        mismatch: None,
+    }
+}
+
+///
+/// # Match when all the matchers match
+///
+/// - `matchers` The matchers
+///
+#[must_use]
+pub fn all_of<T: ?Sized>(matchers: Vec<Box<dyn Matcher<T>>>) -> AllOf<T> {
+    AllOf { matchers }
+}
+
+///
+/// # Match when one of the matchers matches
+///
+/// - `matchers` The matchers
+///
+#[must_use]
+pub fn any_of<T: ?Sized>(matchers: Vec<Box<dyn Matcher<T>>>) -> AnyOf<T> {
+    AnyOf { matchers }
+}
+
+///
+/// # Match when the matcher doesn't match
+///
+/// - `matcher` The matcher
+///
+pub fn not<M>(matcher: M) -> Not<M> {
+    Not { matcher }
+}
+
+///
+/// # Match a value equal to the expected value
+///
+/// - `expected` The expected value
+///
+pub fn eq<'a, T: PartialEq + Debug + 'a>(expected: T) -> Is<'a, T> {
+    is(&format!("equal to {expected:?}"), move |a: &T| {
+        a.eq(&expected)
+    })
+    .showing()
+}
+
+///
+/// # Match a value different of the value
+///
+/// - `unexpected` The unexpected value
+///
+pub fn ne<'a, T: PartialEq + Debug + 'a>(unexpected: T) -> Is<'a, T> {
+    is(&format!("different of {unexpected:?}"), move |a: &T| {
+        a.ne(&unexpected)
+    })
+    .showing()
+}
+
+///
+/// # Match a value greater than the minimum
+///
+/// - `min` The minimum value
+///
+pub fn gt<'a, T: PartialOrd + Debug + 'a>(min: T) -> Is<'a, T> {
+    is(&format!("greater than {min:?}"), move |a: &T| a.gt(&min)).showing()
+}
+
+///
+/// # Match a value greater than or equal to the minimum
+///
+/// - `min` The minimum value
+///
+pub fn ge<'a, T: PartialOrd + Debug + 'a>(min: T) -> Is<'a, T> {
+    is(
+        &format!("greater than or equal to {min:?}"),
+        move |a: &T| a.ge(&min),
+    )
+    .showing()
+}
+
+///
+/// # Match a value lower than the maximum
+///
+/// - `max` The maximum value
+///
+pub fn lt<'a, T: PartialOrd + Debug + 'a>(max: T) -> Is<'a, T> {
+    is(&format!("lower than {max:?}"), move |a: &T| a.lt(&max)).showing()
+}
+
+///
+/// # Match a value lower than or equal to the maximum
+///
+/// - `max` The maximum value
+///
+pub fn le<'a, T: PartialOrd + Debug + 'a>(max: T) -> Is<'a, T> {
+    is(&format!("lower than or equal to {max:?}"), move |a: &T| {
+        a.le(&max)
+    })
+    .showing()
+}
+
+///
+/// # Match a value strictly between the minimum and the maximum
+///
+/// The bounds are excluded, as in `Testable::between`.
+///
+/// - `min` The minimum value
+/// - `max` The maximum value
+///
+pub fn between<'a, T: PartialOrd + Debug + 'a>(min: T, max: T) -> Is<'a, T> {
+    is(&format!("between {min:?} and {max:?}"), move |a: &T| {
+        a.gt(&min) && a.lt(&max)
+    })
+    .showing()
+}
+
+///
+/// # Match a string containing a value
+///
+/// - `needle` The contained value
+///
+#[must_use]
+pub fn str_contains(needle: &str) -> Is<'static, str> {
+    let needle: String = needle.to_string();
+    is(&format!("containing {needle:?}"), move |a: &str| {
+        a.contains(needle.as_str())
+    })
+    .showing()
+}
+
+///
+/// # Match a string beginning with a value
+///
+/// - `prefix` The expected beginning
+///
+#[must_use]
+pub fn begin_with(prefix: &str) -> Is<'static, str> {
+    let prefix: String = prefix.to_string();
+    is(&format!("beginning with {prefix:?}"), move |a: &str| {
+        a.starts_with(prefix.as_str())
+    })
+    .showing()
+}
+
+///
+/// # Match a string ending with a value
+///
+/// - `suffix` The expected end
+///
+#[must_use]
+pub fn end_with(suffix: &str) -> Is<'static, str> {
+    let suffix: String = suffix.to_string();
+    is(&format!("ending with {suffix:?}"), move |a: &str| {
+        a.ends_with(suffix.as_str())
+    })
+    .showing()
+}
+
+///
+/// # Match a string matching a regex
+///
+/// - `pattern` The regex
+///
+/// # Panics
+///
+/// On invalid regex
+///
+#[must_use]
+pub fn matches(pattern: &str) -> Is<'static, str> {
+    let regex: Regex = Regex::new(pattern).expect("invalid regex");
+    is(&format!("matching {pattern:?}"), move |a: &str| {
+        regex.is_match(a)
+    })
+    .showing()
+}
+
+///
+/// # Match a vector containing a value
+///
+/// - `item` The contained value
+///
+pub fn vec_contains<'a, T: PartialEq + Debug + 'a>(item: T) -> Is<'a, Vec<T>> {
+    is(&format!("containing {item:?}"), move |a: &Vec<T>| {
+        a.contains(&item)
+    })
+    .showing()
+}
+
+///
+/// # Match an option containing a value
+///
+/// - `item` The contained value
+///
+pub fn option_contains<'a, T: PartialEq + Debug + 'a>(item: T) -> Is<'a, Option<T>> {
+    is(&format!("containing {item:?}"), move |a: &Option<T>| {
+        a.as_ref().is_some_and(|a| a.eq(&item))
+    })
+    .showing()
+}
+
+///
+/// # Match an existing path
+///
+#[must_use]
+pub fn exists() -> Is<'static, str> {
+    is("an existing path", |a: &str| Path::new(a).exists()).showing()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matcher::{
+        all_of, any_of, between, check, eq, gt, is, lt, matches, not, str_contains, Matcher,
+    };
+
+    #[test]
+    fn combinators() {
+        let range = all_of(vec![Box::new(gt(1)), Box::new(lt(5))]);
+        assert!(range.matches(&3));
+        assert_eq!(range.describe(), "greater than 1 and lower than 5");
+        assert_eq!(range.describe_mismatch(&7), "was 7");
+        let either = any_of(vec![Box::new(eq(1)), Box::new(between(5, 9))]);
+        assert!(either.matches(&6) && !either.matches(&3));
+        assert!(!between(5, 9).matches(&5) && !between(5, 9).matches(&9));
+        assert_eq!(not(eq(2)).describe_mismatch(&2), "was equal to 2");
+        assert!(matches("^[0-9]+$").matches("42"));
+        assert_eq!(
+            check("uuid", &str_contains("-")),
+            (
+                false,
+                String::from("containing \"-\""),
+                String::from("containing \"-\", was \"uuid\"")
+            )
+        );
+        let sorted = is("sorted", |v: &Vec<u8>| v.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(sorted.describe_mismatch(&vec![2, 1]), "was not sorted");
+    }
+}
@@ -1,4 +1,15 @@
+use crate::approx::{Approx, Tolerance};
+use crate::command::Command;
+use crate::executor;
+use crate::matcher::Matcher;
+use crate::property::{self, Config, Generator};
+use crate::report::Report;
+use crate::snapshot;
+use std::fmt::Debug;
+use std::future::Future;
+use std::panic::Location;
 use std::process::ExitStatus;
+use std::time::Duration;
 use std::{collections::HashSet, io};
 
 ///
@@ -35,6 +46,8 @@ pub trait Take {
 ///
 /// # Add theory useful method
 ///
+/// The async and the property theories have default bodies checked with `theory`.
+///
 pub trait Theory {
     ///
     /// # A theory must be equal to false
@@ -58,6 +71,41 @@ pub trait Theory {
     /// - `callback`    The callback to execute
     ///
     fn theory<T: PartialEq>(&mut self, expected: T, callback: &dyn Fn() -> T) -> &mut Self;
+
+    ///
+    /// # Test an async theory
+    ///
+    /// The theory is false if the future is not completed before the timeout.
+    ///
+    /// - `expected`    The expect future output
+    /// - `future`      The future to run
+    ///
+    fn theory_async<T: PartialEq, F: Future<Output = T>>(
+        &mut self,
+        expected: T,
+        future: F,
+    ) -> &mut Self {
+        let equal: bool = executor::block_on(future).eq(&expected);
+        self.theory(true, &|| equal)
+    }
+
+    ///
+    /// # Check a property against generated values
+    ///
+    /// The number of cases and the seed are read from `UNIT_TESTING_CASES` and `UNIT_TESTING_SEED`.
+    /// The first counterexample is shrunk and reported with the seed replaying it.
+    ///
+    /// - `generator`   The values generator
+    /// - `property`    The property to check
+    ///
+    fn for_all<G: Generator>(
+        &mut self,
+        generator: G,
+        property: &dyn Fn(G::Value) -> bool,
+    ) -> &mut Self {
+        let holds: bool = property::check(&Config::from_env(), &generator, property).is_ok();
+        self.theory(true, &|| holds)
+    }
 }
 
 ///
@@ -104,9 +152,12 @@ pub trait Success {
 ///
 /// # The method to implements for a new struct
 ///
+/// The value, async, snapshot and command assertions have default bodies checked with `ok` and `eq`,
+/// without their messages and values.
+///
 pub trait Testable {
     ///
-    /// - `sleep_time` The sleep time
+    /// - `sleep_time` The sleep time between two lines with the animated pacing
     ///
     fn new(sleep_time: u64) -> Self;
 
@@ -148,13 +199,129 @@ pub trait Testable {
     ///
     fn assert(&mut self, test: bool) -> bool;
 
+    ///
+    /// # Check a value with a matcher
+    ///
+    /// - `actual` The actual value
+    /// - `matcher` The matcher describing the expected value
+    ///
+    #[track_caller]
+    fn expect<T: ?Sized, M: Matcher<T>>(&mut self, actual: &T, matcher: M) -> &mut Self {
+        self.ok(matcher.matches(actual))
+    }
+
     ///
     /// # Check if a and b are equals
     ///
     /// - `a` The first value
     /// - `b` The second value
     ///
-    fn eq<T: PartialEq>(&mut self, a: T, b: T) -> &mut Self;
+    fn eq<T: PartialEq + Debug>(&mut self, a: T, b: T) -> &mut Self;
+
+    ///
+    /// # Check if a and b are equals and record their debug output
+    ///
+    /// A diff of the debug outputs is shown on failure.
+    ///
+    /// - `a` The actual value
+    /// - `b` The expected value
+    ///
+    #[track_caller]
+    fn eq_debug<T: PartialEq + Debug>(&mut self, a: T, b: T) -> &mut Self {
+        self.eq(a, b)
+    }
+
+    ///
+    /// # Check if the output of a future equals a value
+    ///
+    /// The assertion fails if the future is not completed before the timeout.
+    ///
+    /// - `a` The future to run
+    /// - `b` The expected value
+    ///
+    #[track_caller]
+    fn eq_async<T: PartialEq, F: Future<Output = T>>(&mut self, a: F, b: T) -> &mut Self {
+        self.ok(executor::block_on(a).eq(&b))
+    }
+
+    ///
+    /// # Check if floats, or slices of floats, are approximately equals
+    ///
+    /// - `actual` The actual value
+    /// - `expected` The expected value
+    /// - `tolerance` The accepted difference
+    ///
+    fn approx_eq<T: Approx + Debug + ?Sized>(
+        &mut self,
+        actual: &T,
+        expected: &T,
+        tolerance: Tolerance,
+    ) -> &mut Self {
+        self.ok(actual.approx(expected, tolerance).is_ok())
+    }
+
+    ///
+    /// # Check if a callback return true before a timeout
+    ///
+    /// The callback runs on a worker thread, the assertion fails if it panics or times out.
+    ///
+    /// - `timeout` The maximum duration
+    /// - `callback` The callback to run
+    ///
+    fn within<F: FnOnce() -> bool + Send + 'static>(
+        &mut self,
+        timeout: Duration,
+        callback: F,
+    ) -> &mut Self {
+        self.ok(matches!(
+            executor::within(Some(timeout), callback),
+            Some(Ok(true))
+        ))
+    }
+
+    ///
+    /// # Check if two strings are equals
+    ///
+    /// A line diff, or a character diff for single line strings, is shown on failure.
+    ///
+    /// - `actual` The actual value
+    /// - `expected` The expected value
+    ///
+    #[track_caller]
+    fn str_eq(&mut self, actual: &str, expected: &str) -> &mut Self {
+        self.eq(actual, expected)
+    }
+
+    ///
+    /// # Check if a value matches its snapshot
+    ///
+    /// The snapshot is stored in a `snapshots` directory next to the test file,
+    /// a `.snap.new` file is written for review on a new or changed snapshot.
+    ///
+    /// - `name` The snapshot name
+    /// - `value` The actual value
+    ///
+    #[track_caller]
+    fn snapshot(&mut self, name: &str, value: &str) -> &mut Self {
+        let file: &str = Location::caller().file();
+        self.ok(snapshot::check(file, name, value).is_ok_and(|s| s.passed()))
+    }
+
+    ///
+    /// # Run a command and check its expectations
+    ///
+    /// Each expectation is recorded, a command that can't be spawned is a failure.
+    ///
+    /// - `command` The command built with `cmd`
+    ///
+    #[track_caller]
+    fn command(&mut self, command: &Command) -> &mut Self {
+        let file: &str = Location::caller().file();
+        command
+            .verify(file)
+            .iter()
+            .fold(self, |s, check| s.ok(check.passed))
+    }
 
     ///
     /// # Check if a and b are unequals
@@ -164,6 +331,17 @@ pub trait Testable {
     ///
     fn ne<T: PartialEq>(&mut self, a: T, b: T) -> &mut Self;
 
+    ///
+    /// # Check if a and b are unequals and record their debug output
+    ///
+    /// - `a` The actual value
+    /// - `b` The unexpected value
+    ///
+    #[track_caller]
+    fn ne_debug<T: PartialEq + Debug>(&mut self, a: T, b: T) -> &mut Self {
+        self.ne(a, b)
+    }
+
     ///
     /// # Check if a is superior to min
     ///
@@ -192,11 +370,13 @@ pub trait Testable {
     ///
     /// # Check if a is between min and max
     ///
+    /// The bounds are excluded.
+    ///
     /// - `a` The first value
     /// - `min` The minimum value
     /// - `max` The maximum value
     ///
-    fn between<T: PartialOrd>(&mut self, a: T, min: T, max: T) -> &mut Self;
+    fn between<T: PartialOrd + Debug>(&mut self, a: T, min: T, max: T) -> &mut Self;
 
     ///
     /// # Check if a vector contains a value
@@ -284,9 +464,29 @@ pub trait Testable {
     fn end_with(&mut self, actual: &str, expected: &str) -> &mut Self;
 
     ///
-    /// # Show assertions
+    /// # Show assertions and return true if the run passed
+    ///
+    /// A report that can't be written is recorded as a failed assertion.
     ///
     fn end(&mut self) -> bool;
+
+    ///
+    /// # Get the recorded assertions
+    ///
+    /// The default report is empty.
+    ///
+    fn report(&self) -> Report {
+        Report::default()
+    }
+
+    ///
+    /// # Show assertions and return the recorded assertions
+    ///
+    fn end_with_report(&mut self) -> Report {
+        let _ = self.end();
+        self.report()
+    }
+
     fn it(
         title: &str,
         description: &str,
@@ -85,4 +85,26 @@ pub const IS_NOT_EXECUTABLE: &str = "The file is not an executable";
 pub const THEORY_IS_TRUE: &str = "The theory is true";
 pub const THEORY_IS_FALSE: &str = "The theory if false";
 pub const IS_NOT_EXISTS: &str = "The path not exists";
+pub const IS_TIMEOUT: &str = "The callback timed out after";
 pub const UNIT_PROGRESS_TIME: u64 = 20;
+pub const SUITE_PROGRESS_TIME: u64 = 50;
+pub const IS_SNAPSHOT_MATCH: &str = "The value match the snapshot";
+pub const IS_SNAPSHOT_NEW: &str = "The snapshot is new, review the .snap.new file";
+pub const IS_SNAPSHOT_CHANGED: &str =
+    "The value don't match the snapshot, review the .snap.new file";
+pub const IS_TEARDOWN_FAILED: &str = "The teardown hook panicked";
+pub const IS_APPROX_EQUALS: &str = "The values are approximately equals";
+pub const IS_NOT_APPROX_EQUALS: &str = "The values are not approximately equals";
+pub const ASSERT_APPROX_EQUALS: &str = "The values are approximately equals";
+pub const ASSERT_SHOULD_BE_APPROX_EQUALS: &str = "The values should be approximately equals";
+pub const IS_SPAWN_FAILED: &str = "The command can't be spawned";
+pub const IS_EXIT_CODE: &str = "The exit code match the expected code";
+pub const IS_NOT_EXIT_CODE: &str = "The exit code don't match the expected code";
+pub const IS_SIGNAL: &str = "The command was terminated by the expected signal";
+pub const IS_NOT_SIGNAL: &str = "The command was not terminated by the expected signal";
+pub const IS_OUTPUT: &str = "The output match the expected content";
+pub const IS_NOT_OUTPUT: &str = "The output don't match the expected content";
+pub const IS_IN_TIME: &str = "The command ended in time";
+pub const IS_NOT_IN_TIME: &str = "The command ended too late";
+pub const IS_REPORT_WRITTEN: &str = "The report is written";
+pub const IS_REPORT_NOT_WRITTEN: &str = "The report can't be written";
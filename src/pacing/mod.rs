@@ -0,0 +1,134 @@
+use std::io::IsTerminal;
+use std::sync::RwLock;
+use std::thread::sleep;
+use std::time::Duration;
+
+///
+/// # The environment variable containing the pacing
+///
+/// The values are `none`, `animated` or a fixed number of milliseconds.
+///
+pub const PACING: &str = "UNIT_TESTING_PACING";
+
+///
+/// # The pacing set by `set`, read before the environment
+///
+static CURRENT: RwLock<Option<Pacing>> = RwLock::new(None);
+
+///
+/// # The pause between two output lines
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pacing {
+    /// Don't pause
+    None,
+    /// Pause with the default time of the runner
+    Animated,
+    /// Pause with a fixed time
+    Fixed(Duration),
+}
+
+impl Pacing {
+    ///
+    /// # Parse a pacing
+    ///
+    /// - `pacing` The pacing name or the number of milliseconds
+    ///
+    #[must_use]
+    pub fn parse(pacing: &str) -> Option<Self> {
+        match pacing.trim() {
+            "none" => Some(Self::None),
+            "animated" => Some(Self::Animated),
+            ms => ms
+                .strip_prefix("fixed:")
+                .unwrap_or(ms)
+                .parse::<u64>()
+                .ok()
+                .map(|ms| Self::Fixed(Duration::from_millis(ms))),
+        }
+    }
+
+    ///
+    /// # Read the pacing from the `UNIT_TESTING_PACING` environment variable
+    ///
+    /// Without it, the pacing is `None` when the stdout is not a terminal or `CI` is defined,
+    /// `Animated` otherwise.
+    ///
+    #[must_use]
+    pub fn from_env() -> Self {
+        if let Some(pacing) = std::env::var(PACING).ok().and_then(|p| Self::parse(&p)) {
+            return pacing;
+        }
+        if std::env::var_os("CI").is_some() || !std::io::stdout().is_terminal() {
+            Self::None
+        } else {
+            Self::Animated
+        }
+    }
+
+    ///
+    /// # Pause
+    ///
+    /// - `animated` The default time of the runner
+    ///
+    pub fn pause(&self, animated: Duration) {
+        match self {
+            Self::None => {}
+            Self::Animated => sleep(animated),
+            Self::Fixed(time) => sleep(*time),
+        }
+    }
+}
+
+///
+/// # Set the pacing of all the runners
+///
+/// - `pacing` The pacing
+///
+pub fn set(pacing: Pacing) {
+    if let Ok(mut current) = CURRENT.write() {
+        *current = Some(pacing);
+    }
+}
+
+///
+/// # Get the pacing set by `set`, or read from the environment
+///
+#[must_use]
+pub fn current() -> Pacing {
+    CURRENT
+        .read()
+        .ok()
+        .and_then(|current| *current)
+        .unwrap_or_else(Pacing::from_env)
+}
+
+///
+/// # Pause with the current pacing
+///
+/// - `animated` The default time of the runner
+///
+pub fn pause(animated: Duration) {
+    current().pause(animated);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::pacing::Pacing;
+    use std::time::Duration;
+
+    #[test]
+    fn parse() {
+        assert_eq!(Pacing::parse("none"), Some(Pacing::None));
+        assert_eq!(Pacing::parse("animated"), Some(Pacing::Animated));
+        assert_eq!(
+            Pacing::parse("fixed:5"),
+            Some(Pacing::Fixed(Duration::from_millis(5)))
+        );
+        assert_eq!(
+            Pacing::parse("10"),
+            Some(Pacing::Fixed(Duration::from_millis(10)))
+        );
+        assert_eq!(Pacing::parse("fast"), None);
+    }
+}
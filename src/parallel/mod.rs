@@ -0,0 +1,92 @@
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+///
+/// # The environment variable containing the maximum number of workers
+///
+/// The available parallelism is used when it's not defined.
+///
+pub const WORKERS: &str = "UNIT_TESTING_WORKERS";
+
+///
+/// # Get the number of workers running a number of jobs
+///
+/// - `jobs` The number of jobs
+///
+#[must_use]
+pub fn workers(jobs: usize) -> usize {
+    std::env::var(WORKERS)
+        .ok()
+        .and_then(|w| w.parse::<usize>().ok())
+        .filter(|w| w.gt(&0))
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, NonZeroUsize::get))
+        .min(jobs)
+        .max(1)
+}
+
+///
+/// # Run jobs on a pool of workers
+///
+/// The results are returned in the jobs order, a panicking job gives an `Err`.
+///
+/// - `jobs` The jobs to run
+/// - `worker` The callback running a job
+///
+pub fn run<J: Send, R: Send>(
+    jobs: Vec<J>,
+    worker: &(dyn Fn(J) -> R + Sync),
+) -> Vec<thread::Result<R>> {
+    let total: usize = jobs.len();
+    let jobs: Vec<Mutex<Option<J>>> = jobs.into_iter().map(|j| Mutex::new(Some(j))).collect();
+    let results: Vec<Mutex<Option<thread::Result<R>>>> =
+        (0..total).map(|_| Mutex::new(None)).collect();
+    let next: AtomicUsize = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        for _ in 0..workers(total) {
+            scope.spawn(|| loop {
+                let i: usize = next.fetch_add(1, Ordering::SeqCst);
+                let Some(job) = jobs.get(i).and_then(|j| j.lock().ok()?.take()) else {
+                    break;
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| worker(job)));
+                if let Ok(mut slot) = results[i].lock() {
+                    *slot = Some(result);
+                }
+            });
+        }
+    });
+    results
+        .into_iter()
+        .map(|r| {
+            r.into_inner()
+                .ok()
+                .flatten()
+                .expect("a job has not been run")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parallel::{run, workers};
+
+    #[test]
+    fn ordered() {
+        assert!(workers(0).eq(&1));
+        let results = run((0..64).collect(), &|x: u64| {
+            assert!(x.ne(&7), "the job panicked");
+            x * 2
+        });
+        assert_eq!(results.len(), 64);
+        assert!(results[7].is_err());
+        assert_eq!(results[63].as_ref().ok(), Some(&126));
+        assert!(results
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i.ne(&7))
+            .all(|(i, r)| r.as_ref().ok().eq(&Some(&(i as u64 * 2)))));
+    }
+}
@@ -0,0 +1,537 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+///
+/// # The environment variable containing the seed to replay
+///
+pub const SEED: &str = "UNIT_TESTING_SEED";
+
+///
+/// # The environment variable containing the number of generated cases
+///
+pub const CASES: &str = "UNIT_TESTING_CASES";
+
+///
+/// # The default number of generated cases
+///
+pub const DEFAULT_CASES: usize = 256;
+
+///
+/// # The maximum number of shrinking steps
+///
+const SHRINK_LIMIT: usize = 1024;
+
+///
+/// # The maximum length of a generated string or vector
+///
+const MAX_LENGTH: u64 = 32;
+
+///
+/// # A seedable pseudo random generator (splitmix64)
+///
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    ///
+    /// # Create a generator from a seed
+    ///
+    /// - `seed` The seed
+    ///
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    ///
+    /// # Generate the next random number
+    ///
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    ///
+    /// # Generate a number lower than a bound
+    ///
+    /// - `bound` The exclusive upper bound, `0` means no bound
+    ///
+    pub fn below(&mut self, bound: u64) -> u64 {
+        if bound.eq(&0) {
+            self.next_u64()
+        } else {
+            self.next_u64() % bound
+        }
+    }
+
+    ///
+    /// # Generate a float in `[0, 1)`
+    ///
+    #[allow(clippy::cast_precision_loss)]
+    pub fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    ///
+    /// # Check a one in `n` chance
+    ///
+    /// - `n` The chance denominator
+    ///
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.below(n).eq(&0)
+    }
+}
+
+///
+/// # The property testing configuration
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The number of generated cases
+    pub cases: usize,
+    /// The seed of the random generator
+    pub seed: u64,
+}
+
+impl Config {
+    ///
+    /// # Read the configuration from the `UNIT_TESTING_CASES` and `UNIT_TESTING_SEED` environment variables
+    ///
+    /// A random seed is used when `UNIT_TESTING_SEED` is not defined.
+    ///
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self {
+            cases: std::env::var(CASES)
+                .ok()
+                .and_then(|c| c.parse().ok())
+                .unwrap_or(DEFAULT_CASES),
+            seed: std::env::var(SEED)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or_else(random_seed),
+        }
+    }
+}
+
+///
+/// # Build a seed from the current time
+///
+fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos() as u64)
+}
+
+///
+/// # A type able to generate and shrink its values
+///
+pub trait Arbitrary: Clone + Debug + Sized {
+    ///
+    /// # Generate a value
+    ///
+    /// - `rng` The random generator
+    ///
+    fn arbitrary(rng: &mut Rng) -> Self;
+
+    ///
+    /// # List simpler values to try when the value is a counterexample
+    ///
+    fn shrink(&self) -> Vec<Self> {
+        Vec::new()
+    }
+}
+
+///
+/// # A generator of values for a property
+///
+pub trait Generator {
+    type Value: Clone + Debug;
+
+    ///
+    /// # Generate a value
+    ///
+    /// - `rng` The random generator
+    ///
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    ///
+    /// # List simpler values to try when the value is a counterexample
+    ///
+    /// - `value` The counterexample
+    ///
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+///
+/// # The generator of an arbitrary type
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Any<T>(PhantomData<T>);
+
+///
+/// # Generate any value of an arbitrary type
+///
+#[must_use]
+pub const fn any<T: Arbitrary>() -> Any<T> {
+    Any(PhantomData)
+}
+
+impl<T: Arbitrary> Generator for Any<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        T::arbitrary(rng)
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        value.shrink()
+    }
+}
+
+///
+/// # The generator of integers between two inclusive bounds
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Between<T> {
+    min: T,
+    max: T,
+}
+
+///
+/// # Generate integers between two inclusive bounds
+///
+/// - `min` The minimum value
+/// - `max` The maximum value
+///
+/// # Panics
+///
+/// If `min` is greater than `max`
+///
+#[must_use]
+pub fn between<T: PartialOrd + Debug>(min: T, max: T) -> Between<T> {
+    assert!(
+        min <= max,
+        "The minimum {min:?} must not be greater than the maximum {max:?}"
+    );
+    Between { min, max }
+}
+
+macro_rules! integers {
+    ($($t:ty),*) => {
+        $(
+            impl Arbitrary for $t {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    if rng.one_in(8) {
+                        let edges: [$t; 4] = [0, 1, <$t>::MIN, <$t>::MAX];
+                        edges[rng.below(4) as usize]
+                    } else {
+                        rng.next_u64() as $t
+                    }
+                }
+
+                #[allow(unused_comparisons)]
+                fn shrink(&self) -> Vec<Self> {
+                    let x: $t = *self;
+                    let mut all: Vec<$t> = Vec::new();
+                    for c in [0, x / 2, if x < 0 { x + 1 } else { x.saturating_sub(1) }] {
+                        if c.ne(&x) && !all.contains(&c) {
+                            all.push(c);
+                        }
+                    }
+                    all
+                }
+            }
+
+            impl Generator for Between<$t> {
+                type Value = $t;
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_lossless)]
+                fn generate(&self, rng: &mut Rng) -> $t {
+                    let width: u128 = (self.max as i128 - self.min as i128) as u128 + 1;
+                    let offset: u128 = (rng.next_u64() as u128) % width;
+                    (self.min as i128 + offset as i128) as $t
+                }
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+                fn shrink(&self, value: &$t) -> Vec<$t> {
+                    let x: $t = *value;
+                    let middle: $t = ((self.min as i128 + x as i128) / 2) as $t;
+                    let mut all: Vec<$t> = Vec::new();
+                    for c in [self.min, middle, x.saturating_sub(1)] {
+                        if c.ne(&x) && c >= self.min && !all.contains(&c) {
+                            all.push(c);
+                        }
+                    }
+                    all
+                }
+            }
+        )*
+    };
+}
+
+integers!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! floats {
+    ($($t:ty),*) => {
+        $(
+            impl Arbitrary for $t {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    if rng.one_in(8) {
+                        return 0.0;
+                    }
+                    let scale: f64 = 10_f64.powi(rng.below(7) as i32);
+                    ((rng.unit() * 2.0 - 1.0) * scale) as $t
+                }
+
+                #[allow(clippy::float_cmp)]
+                fn shrink(&self) -> Vec<Self> {
+                    let x: $t = *self;
+                    let mut all: Vec<$t> = Vec::new();
+                    for c in [0.0, x.trunc(), (x / 2.0).trunc()] {
+                        if c.ne(&x) && !all.contains(&c) {
+                            all.push(c);
+                        }
+                    }
+                    all
+                }
+            }
+        )*
+    };
+}
+
+floats!(f32, f64);
+
+impl Arbitrary for bool {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        rng.one_in(2)
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            vec![false]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl Arbitrary for char {
+    #[allow(clippy::cast_possible_truncation)]
+    fn arbitrary(rng: &mut Rng) -> Self {
+        if rng.one_in(8) {
+            ['é', 'ß', '€', '日', '😀', '\n', '\t', '"'][rng.below(8) as usize]
+        } else {
+            char::from(b' ' + rng.below(95) as u8)
+        }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        if self.eq(&'a') {
+            Vec::new()
+        } else {
+            vec!['a']
+        }
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Vec<T> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn arbitrary(rng: &mut Rng) -> Self {
+        let length: usize = rng.below(MAX_LENGTH + 1) as usize;
+        (0..length).map(|_| T::arbitrary(rng)).collect()
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut all: Vec<Self> = Vec::new();
+        if self.is_empty() {
+            return all;
+        }
+        all.push(Vec::new());
+        let half: usize = self.len() / 2;
+        if half > 0 {
+            all.push(self[..half].to_vec());
+            all.push(self[half..].to_vec());
+        }
+        for i in 0..self.len() {
+            let mut v: Self = self.clone();
+            v.remove(i);
+            all.push(v);
+        }
+        for (i, x) in self.iter().enumerate() {
+            if let Some(s) = x.shrink().into_iter().next() {
+                let mut v: Self = self.clone();
+                v[i] = s;
+                all.push(v);
+            }
+        }
+        all
+    }
+}
+
+impl Arbitrary for String {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        Vec::<char>::arbitrary(rng).into_iter().collect()
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        self.chars()
+            .collect::<Vec<char>>()
+            .shrink()
+            .into_iter()
+            .map(|c| c.into_iter().collect())
+            .collect()
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Option<T> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        if rng.one_in(4) {
+            None
+        } else {
+            Some(T::arbitrary(rng))
+        }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            None => Vec::new(),
+            Some(x) => std::iter::once(None)
+                .chain(x.shrink().into_iter().map(Some))
+                .collect(),
+        }
+    }
+}
+
+impl<A: Arbitrary, B: Arbitrary> Arbitrary for (A, B) {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        (A::arbitrary(rng), B::arbitrary(rng))
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b) = self;
+        a.shrink()
+            .into_iter()
+            .map(|x| (x, b.clone()))
+            .chain(b.shrink().into_iter().map(|y| (a.clone(), y)))
+            .collect()
+    }
+}
+
+///
+/// # A value falsifying a property
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counterexample<T> {
+    /// The seed replaying the failure
+    pub seed: u64,
+    /// The failing case number, starting at 1
+    pub case: usize,
+    /// The first generated failing value
+    pub original: T,
+    /// The shrunk failing value
+    pub value: T,
+    /// The number of successful shrinking steps
+    pub shrinks: usize,
+}
+
+impl<T: Debug> Counterexample<T> {
+    ///
+    /// # Build the failure message
+    ///
+    /// - `e` The error output message
+    ///
+    #[must_use]
+    pub fn message(&self, e: &str) -> String {
+        format!(
+            "{e} for {:?} (case {}, {} shrinks), replay with {SEED}={}",
+            self.value, self.case, self.shrinks, self.seed
+        )
+    }
+}
+
+///
+/// # Check a property against generated values
+///
+/// Return the number of passed cases, or the shrunk first counterexample.
+///
+/// - `config` The configuration
+/// - `generator` The values generator
+/// - `property` The property to check
+///
+/// # Errors
+///
+/// When a generated value falsifies the property
+///
+pub fn check<G: Generator>(
+    config: &Config,
+    generator: &G,
+    property: &dyn Fn(G::Value) -> bool,
+) -> Result<usize, Counterexample<G::Value>> {
+    let mut rng: Rng = Rng::new(config.seed);
+    for case in 1..=config.cases {
+        let original: G::Value = generator.generate(&mut rng);
+        if property(original.clone()) {
+            continue;
+        }
+        let mut value: G::Value = original.clone();
+        let mut shrinks: usize = 0;
+        while shrinks < SHRINK_LIMIT {
+            match generator
+                .shrink(&value)
+                .into_iter()
+                .find(|candidate| !property(candidate.clone()))
+            {
+                Some(simpler) => {
+                    value = simpler;
+                    shrinks += 1;
+                }
+                None => break,
+            }
+        }
+        return Err(Counterexample {
+            seed: config.seed,
+            case,
+            original,
+            value,
+            shrinks,
+        });
+    }
+    Ok(config.cases)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::property::{any, between, check, Config, Generator, Rng};
+
+    #[test]
+    fn shrinking() {
+        let config = Config {
+            cases: 500,
+            seed: 42,
+        };
+        assert_eq!(
+            check(&config, &any::<Vec<i32>>(), &|v| v.len() < 1_000),
+            Ok(500)
+        );
+        let counterexample = check(&config, &any::<u32>(), &|x| x < 100).unwrap_err();
+        assert_eq!(counterexample.value, 100);
+        assert_eq!(counterexample.seed, 42);
+        let counterexample = check(&config, &any::<Vec<u8>>(), &|v| !v.contains(&7)).unwrap_err();
+        assert_eq!(counterexample.value, vec![7]);
+        let mut rng = Rng::new(7);
+        let range = between(-3_i64, 3);
+        assert!((0..100).all(|_| (-3..=3).contains(&range.generate(&mut rng))));
+        let range = between(u64::MAX, u64::MAX);
+        assert_eq!(range.generate(&mut rng), u64::MAX);
+        assert!(std::panic::catch_unwind(|| between(5, 4)).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use std::panic::Location;
+
+use crate::diff;
+
+///
+/// # The environment variable containing the output format
+///
+pub const FORMAT: &str = "UNIT_TESTING_FORMAT";
+
+///
+/// # The output format of `Unit`, `Assert` and `Suite`
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// The coloured output with progress bars
+    Fancy,
+    /// The Test Anything Protocol output with the protocol version
+    Tap(u8),
+    /// The newline delimited json event stream
+    Json,
+}
+
+impl Format {
+    ///
+    /// # Read the format from the `UNIT_TESTING_FORMAT` environment variable
+    ///
+    /// Accept `tap`, `tap13`, `tap14` and `json`, any other value is the fancy output.
+    ///
+    #[must_use]
+    pub fn from_env() -> Self {
+        match std::env::var(FORMAT).unwrap_or_default().as_str() {
+            "tap" | "tap14" => Self::Tap(14),
+            "tap13" => Self::Tap(13),
+            "json" => Self::Json,
+            _ => Self::Fancy,
+        }
+    }
+}
+
+///
+/// # The outcome of an assertion
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed,
+    Skipped,
+    Todo,
+}
+
+///
+/// # A recorded assertion
+///
+#[derive(Clone, Debug)]
+pub struct Record {
+    /// The assertion kind (`eq`, `str_contains`, ...)
+    pub kind: String,
+    /// The output message
+    pub message: String,
+    /// The assertion outcome
+    pub outcome: Outcome,
+    /// The elapsed time in nanoseconds, from the start of the tested callback or future,
+    /// only the check itself for a value
+    pub take: u128,
+    /// The group or the title the assertion belongs to
+    pub group: String,
+    /// The source location of the assertion
+    pub location: Option<&'static Location<'static>>,
+    /// The expected value if known
+    pub expected: Option<String>,
+    /// The actual value if known
+    pub actual: Option<String>,
+}
+
+impl Record {
+    ///
+    /// # Create a new record
+    ///
+    /// - `kind` The assertion kind
+    /// - `message` The output message
+    /// - `outcome` The assertion outcome
+    /// - `take` The elapsed time in nanoseconds
+    /// - `group` The group title
+    ///
+    #[must_use]
+    pub fn new(kind: &str, message: &str, outcome: Outcome, take: u128, group: &str) -> Self {
+        Self {
+            kind: kind.to_string(),
+            message: message.to_string(),
+            outcome,
+            take,
+            group: group.to_string(),
+            location: None,
+            expected: None,
+            actual: None,
+        }
+    }
+
+    ///
+    /// # Attach the expected and the actual values
+    ///
+    /// - `expected` The expected value
+    /// - `actual` The actual value
+    ///
+    #[must_use]
+    pub fn with_values(mut self, expected: &str, actual: &str) -> Self {
+        self.expected = Some(expected.to_string());
+        self.actual = Some(actual.to_string());
+        self
+    }
+
+    ///
+    /// # Build the coloured diff between the expected and the actual values
+    ///
+    #[must_use]
+    pub fn diff(&self) -> Option<String> {
+        match (&self.expected, &self.actual) {
+            (Some(expected), Some(actual)) => Some(diff::diff(expected, actual)),
+            _ => None,
+        }
+    }
+
+    ///
+    /// # Format the source location as `file:line:column`
+    ///
+    #[must_use]
+    pub fn at(&self) -> Option<String> {
+        self.location
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+    }
+
+    ///
+    /// # Check if the assertion passed
+    ///
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.outcome.eq(&Outcome::Passed)
+    }
+
+    ///
+    /// # Check if the assertion failed
+    ///
+    #[must_use]
+    pub fn failed(&self) -> bool {
+        self.outcome.eq(&Outcome::Failed)
+    }
+
+    ///
+    /// # Check if the assertion has been skipped
+    ///
+    #[must_use]
+    pub fn skipped(&self) -> bool {
+        self.outcome.eq(&Outcome::Skipped)
+    }
+
+    ///
+    /// # Check if the assertion is a failing todo
+    ///
+    #[must_use]
+    pub fn todo(&self) -> bool {
+        self.outcome.eq(&Outcome::Todo)
+    }
+}
+
+///
+/// # The result of a `Unit`, `Assert` or `Suite` run
+///
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    /// The run title
+    pub title: String,
+    /// The run description
+    pub description: String,
+    /// The recorded assertions
+    pub records: Vec<Record>,
+}
+
+impl Report {
+    ///
+    /// # Create an empty report
+    ///
+    /// - `title` The run title
+    /// - `description` The run description
+    ///
+    #[must_use]
+    pub fn new(title: &str, description: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            description: description.to_string(),
+            records: Vec::new(),
+        }
+    }
+
+    ///
+    /// # Count the passed assertions
+    ///
+    #[must_use]
+    pub fn assertions(&self) -> usize {
+        self.records.iter().filter(|r| r.passed()).count()
+    }
+
+    ///
+    /// # Count the failed assertions
+    ///
+    #[must_use]
+    pub fn failures(&self) -> usize {
+        self.records.iter().filter(|r| r.failed()).count()
+    }
+
+    ///
+    /// # Count the skipped assertions
+    ///
+    #[must_use]
+    pub fn skipped(&self) -> usize {
+        self.records.iter().filter(|r| r.skipped()).count()
+    }
+
+    ///
+    /// # Count the failing todo assertions
+    ///
+    #[must_use]
+    pub fn todo(&self) -> usize {
+        self.records.iter().filter(|r| r.todo()).count()
+    }
+
+    ///
+    /// # Count all the assertions
+    ///
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.records.len()
+    }
+
+    ///
+    /// # Sum the elapsed time of all assertions in nanoseconds
+    ///
+    #[must_use]
+    pub fn take(&self) -> u128 {
+        self.records.iter().map(|r| r.take).sum()
+    }
+
+    ///
+    /// # Check if no assertion failed
+    ///
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.failures().eq(&0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::report::{Outcome, Record, Report};
+
+    #[test]
+    fn counters() {
+        let mut report = Report::new("report", "Check the report counters");
+        report
+            .records
+            .push(Record::new("ok", "passed", Outcome::Passed, 10, "a"));
+        report
+            .records
+            .push(Record::new("ko", "failed", Outcome::Failed, 5, "a"));
+        report
+            .records
+            .push(Record::new("group", "skipped", Outcome::Skipped, 0, "b"));
+        report
+            .records
+            .push(Record::new("ok", "todo", Outcome::Todo, 0, "b"));
+        assert_eq!(report.total(), 4);
+        assert_eq!(report.skipped(), 1);
+        assert_eq!(report.todo(), 1);
+        assert_eq!(report.assertions(), 1);
+        assert_eq!(report.failures(), 1);
+        assert_eq!(report.take(), 15);
+        assert!(!report.is_success());
+    }
+}
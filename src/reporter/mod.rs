@@ -0,0 +1,255 @@
+use std::io;
+#[cfg(test)]
+use std::sync::{Arc, Mutex};
+
+use crate::console::{self, ConsoleReporter, ProgressReporter};
+use crate::json::JsonReporter;
+use crate::junit::{JunitReporter, JUNIT_DIRECTORY};
+use crate::report::{Format, Record, Report};
+use crate::tap::TapReporter;
+
+///
+/// # Receive the events of a run
+///
+/// All the hooks do nothing by default, a reporter implements the events it needs.
+/// The reporters of a run are called in their registration order.
+///
+pub trait Reporter: Send {
+    ///
+    /// # A run is started
+    ///
+    /// - `title` The run title
+    /// - `description` The run description
+    ///
+    fn suite_started(&mut self, title: &str, description: &str) {
+        let _ = (title, description);
+    }
+
+    ///
+    /// # A group is started
+    ///
+    /// - `title` The group title
+    /// - `description` The group description
+    /// - `depth` The group depth, 1 for a group of the run
+    ///
+    fn group_started(&mut self, title: &str, description: &str, depth: usize) {
+        let _ = (title, description, depth);
+    }
+
+    ///
+    /// # A titled theory is started
+    ///
+    /// - `title` The theory title
+    /// - `description` The theory description
+    /// - `depth` The depth of the group containing the theory
+    ///
+    fn theory_started(&mut self, title: &str, description: &str, depth: usize) {
+        let _ = (title, description, depth);
+    }
+
+    ///
+    /// # An assertion is recorded
+    ///
+    /// - `record` The assertion
+    /// - `depth` The depth of the group containing the assertion
+    ///
+    fn assertion(&mut self, record: &Record, depth: usize) {
+        let _ = (record, depth);
+    }
+
+    ///
+    /// # A hook is executed
+    ///
+    /// - `name` The hook name, `before_all`, `before_each`, `after_each` or `after_all`
+    ///
+    fn hook_executed(&mut self, name: &str) {
+        let _ = name;
+    }
+
+    ///
+    /// # A group is finished
+    ///
+    /// - `title` The group title
+    /// - `depth` The group depth
+    ///
+    fn group_finished(&mut self, title: &str, depth: usize) {
+        let _ = (title, depth);
+    }
+
+    ///
+    /// # A run is finished
+    ///
+    /// - `report` The report of the run
+    ///
+    /// # Errors
+    ///
+    /// On report write failure
+    ///
+    fn suite_finished(&mut self, report: &Report) -> io::Result<()> {
+        let _ = report;
+        Ok(())
+    }
+}
+
+///
+/// # Build the reporters selected by the environment
+///
+/// The `UNIT_TESTING_FORMAT` reporter, with the console reporter of the runner for the default format,
+/// and a junit reporter when `UNIT_TESTING_JUNIT` is defined.
+///
+/// - `console` The console reporter of the runner
+///
+#[must_use]
+pub fn defaults(console: Box<dyn Reporter>) -> Vec<Box<dyn Reporter>> {
+    let mut reporters: Vec<Box<dyn Reporter>> = vec![match Format::from_env() {
+        Format::Fancy => console,
+        Format::Tap(version) => Box::new(TapReporter::new(version)),
+        Format::Json => Box::new(JsonReporter::default()),
+    }];
+    if std::env::var(JUNIT_DIRECTORY).is_ok_and(|d| !d.is_empty()) {
+        reporters.push(Box::new(JunitReporter::default()));
+    }
+    reporters
+}
+
+///
+/// # Build the reporters of a suite
+///
+#[must_use]
+pub fn suite() -> Vec<Box<dyn Reporter>> {
+    defaults(Box::new(ConsoleReporter::new(console::current())))
+}
+
+///
+/// # Build the reporters of a unit or an assert run
+///
+/// - `sleep` The sleep time between two lines with the animated pacing
+/// - `strict` The run prohibits the failures
+///
+#[must_use]
+pub fn progress(sleep: u64, strict: bool) -> Vec<Box<dyn Reporter>> {
+    defaults(Box::new(ProgressReporter::new(
+        console::current(),
+        sleep,
+        strict,
+    )))
+}
+
+///
+/// # Finish a run on all the reporters
+///
+/// All the reporters are finished, the first error is returned.
+///
+/// - `reporters` The reporters
+/// - `report` The report of the run
+///
+/// # Errors
+///
+/// On report write failure
+///
+pub fn finish(reporters: &mut [Box<dyn Reporter>], report: &Report) -> io::Result<()> {
+    let mut result: io::Result<()> = Ok(());
+    for reporter in reporters.iter_mut() {
+        let finished: io::Result<()> = reporter.suite_finished(report);
+        if result.is_ok() {
+            result = finished;
+        }
+    }
+    result
+}
+
+///
+/// # A reporter recording the events it receives
+///
+/// The clones share the recorded events.
+///
+#[cfg(test)]
+#[derive(Clone, Default)]
+pub(crate) struct Recorder {
+    /// The recorded events
+    events: Arc<Mutex<Vec<String>>>,
+    /// The error returned at the end of the run
+    failure: Option<String>,
+}
+
+#[cfg(test)]
+impl Recorder {
+    ///
+    /// # Fail at the end of the run
+    ///
+    /// - `message` The error message
+    ///
+    pub(crate) fn failing(mut self, message: &str) -> Self {
+        self.failure = Some(message.to_string());
+        self
+    }
+
+    ///
+    /// # Get the recorded events
+    ///
+    pub(crate) fn events(&self) -> Vec<String> {
+        self.events.lock().map(|e| e.clone()).unwrap_or_default()
+    }
+
+    ///
+    /// # Record an event
+    ///
+    /// - `event` The event
+    ///
+    fn record(&self, event: String) {
+        if let Ok(mut events) = self.events.lock() {
+            events.push(event);
+        }
+    }
+}
+
+#[cfg(test)]
+impl Reporter for Recorder {
+    fn suite_started(&mut self, title: &str, _description: &str) {
+        self.record(title.to_string());
+    }
+
+    fn group_started(&mut self, title: &str, _description: &str, depth: usize) {
+        self.record(format!("{title} {depth}"));
+    }
+
+    fn theory_started(&mut self, title: &str, _description: &str, depth: usize) {
+        self.record(format!("{title} {depth}"));
+    }
+
+    fn assertion(&mut self, record: &Record, depth: usize) {
+        self.record(format!("{} {depth}", record.kind));
+    }
+
+    fn suite_finished(&mut self, _report: &Report) -> io::Result<()> {
+        self.failure
+            .as_ref()
+            .map_or(Ok(()), |message| Err(io::Error::other(message.clone())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::report::{Outcome, Record, Report};
+    use crate::reporter::{finish, Recorder, Reporter};
+
+    #[test]
+    fn hooks() {
+        let recorder = Recorder::default();
+        let mut reporters: Vec<Box<dyn Reporter>> = vec![
+            Box::new(recorder.clone().failing("first")),
+            Box::new(recorder.clone().failing("second")),
+        ];
+        let record = Record::new("eq", "equals", Outcome::Passed, 0, "hooks");
+        for reporter in &mut reporters {
+            reporter.suite_started("hooks", "Check the default hooks");
+            reporter.assertion(&record, 1);
+        }
+        let report = Report::new("first", "");
+        assert_eq!(
+            finish(&mut reporters, &report).map_err(|e| e.to_string()),
+            Err(String::from("first"))
+        );
+        assert_eq!(recorder.events(), vec!["hooks", "eq 1", "hooks", "eq 1"]);
+    }
+}
@@ -0,0 +1,213 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+///
+/// # The environment variable accepting the pending snapshots when set to `1` or `accept`
+///
+pub const SNAPSHOT_UPDATE: &str = "UNIT_TESTING_SNAPSHOT";
+
+///
+/// # The directory containing the snapshots, next to the test file
+///
+pub const SNAPSHOT_DIRECTORY: &str = "snapshots";
+
+///
+/// # The result of a snapshot comparison
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Snapshot {
+    /// The value matches the stored snapshot
+    Matched,
+    /// The value has been stored as the snapshot
+    Accepted,
+    /// No snapshot was stored, the value is pending review
+    New,
+    /// The value differs from the stored snapshot, the stored value is given
+    Changed(String),
+}
+
+impl Snapshot {
+    ///
+    /// # Check if the comparison passed
+    ///
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        matches!(self, Self::Matched | Self::Accepted)
+    }
+}
+
+///
+/// # Check if the pending snapshots must be accepted
+///
+#[must_use]
+pub fn accepting() -> bool {
+    matches!(
+        std::env::var(SNAPSHOT_UPDATE).unwrap_or_default().as_str(),
+        "1" | "accept"
+    )
+}
+
+///
+/// # Resolve a test file given relative to the workspace root
+///
+/// The file is searched from `CARGO_MANIFEST_DIR` up to its ancestors,
+/// so that a workspace member finds its own files.
+///
+/// - `file` The test file
+///
+fn resolve(file: &str) -> PathBuf {
+    let relative: &Path = Path::new(file);
+    if relative.is_absolute() {
+        return relative.to_path_buf();
+    }
+    let manifest: PathBuf = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_default();
+    manifest
+        .ancestors()
+        .map(|root| root.join(relative))
+        .find(|candidate| candidate.exists())
+        .unwrap_or_else(|| manifest.join(relative))
+}
+
+///
+/// # Build the snapshot path of a test file
+///
+/// - `file` The test file, absolute or relative to the workspace root
+/// - `name` The snapshot name
+///
+#[must_use]
+pub fn path(file: &str, name: &str) -> PathBuf {
+    resolve(file)
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(SNAPSHOT_DIRECTORY)
+        .join(format!("{name}.snap"))
+}
+
+///
+/// # Build the pending snapshot path of a snapshot
+///
+/// - `snap` The snapshot path
+///
+fn pending(snap: &Path) -> PathBuf {
+    snap.with_extension("snap.new")
+}
+
+///
+/// # Compare a value with the snapshot of a test file
+///
+/// The snapshot is stored when `UNIT_TESTING_SNAPSHOT` accepts the pending snapshots,
+/// a `.snap.new` file is written otherwise on a new or changed snapshot.
+///
+/// - `file` The test file
+/// - `name` The snapshot name
+/// - `value` The actual value
+///
+/// # Errors
+///
+/// On snapshot read or write failure
+///
+pub fn check(file: &str, name: &str, value: &str) -> io::Result<Snapshot> {
+    compare(&path(file, name), value, accepting())
+}
+
+///
+/// # Compare a value with a snapshot file
+///
+/// - `snap` The snapshot path
+/// - `value` The actual value
+/// - `accept` Store the value as the snapshot
+///
+/// # Errors
+///
+/// On snapshot read or write failure
+///
+pub fn compare(snap: &Path, value: &str, accept: bool) -> io::Result<Snapshot> {
+    let new: PathBuf = pending(snap);
+    let stored: Option<String> = match fs::read_to_string(snap) {
+        Ok(content) => Some(content),
+        Err(e) if e.kind().eq(&io::ErrorKind::NotFound) => None,
+        Err(e) => return Err(e),
+    };
+    if stored.as_deref().eq(&Some(value)) {
+        if new.exists() {
+            fs::remove_file(&new)?;
+        }
+        return Ok(Snapshot::Matched);
+    }
+    if let Some(directory) = snap.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    if accept {
+        fs::write(snap, value)?;
+        if new.exists() {
+            fs::remove_file(&new)?;
+        }
+        return Ok(Snapshot::Accepted);
+    }
+    fs::write(&new, value)?;
+    Ok(stored.map_or(Snapshot::New, Snapshot::Changed))
+}
+
+///
+/// # Accept all the pending snapshots of a directory
+///
+/// Return the number of accepted snapshots.
+///
+/// - `directory` The snapshots directory
+///
+/// # Errors
+///
+/// On directory read or file rename failure
+///
+pub fn accept_all(directory: &Path) -> io::Result<usize> {
+    let mut accepted: usize = 0;
+    for entry in fs::read_dir(directory)? {
+        let new: PathBuf = entry?.path();
+        if new.to_string_lossy().ends_with(".snap.new") {
+            fs::rename(&new, new.with_extension(""))?;
+            accepted += 1;
+        }
+    }
+    Ok(accepted)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::snapshot::{accept_all, compare, path, Snapshot};
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn review() {
+        let file = std::env::temp_dir()
+            .join(format!("unit-testing-snapshot-{}", std::process::id()))
+            .join("mod.rs");
+        let snap = path(&file.to_string_lossy(), "review");
+        let _ = fs::remove_dir_all(snap.parent().expect("no snapshot directory"));
+        assert_eq!(compare(&snap, "a\nb", false).ok(), Some(Snapshot::New));
+        assert!(!snap.exists());
+        assert_eq!(
+            accept_all(snap.parent().expect("no snapshot directory")).ok(),
+            Some(1)
+        );
+        assert_eq!(compare(&snap, "a\nb", false).ok(), Some(Snapshot::Matched));
+        assert_eq!(
+            compare(&snap, "a\nc", false).ok(),
+            Some(Snapshot::Changed(String::from("a\nb")))
+        );
+        assert!(snap.with_extension("snap.new").exists());
+        assert_eq!(compare(&snap, "a\nc", true).ok(), Some(Snapshot::Accepted));
+        assert!(!snap.with_extension("snap.new").exists());
+        assert_eq!(fs::read_to_string(&snap).ok().as_deref(), Some("a\nc"));
+        let _ = fs::remove_dir_all(snap.parent().expect("no snapshot directory"));
+        let manifest = env!("CARGO_MANIFEST_DIR");
+        assert_eq!(
+            path("src/suite/mod.rs", "equals"),
+            Path::new(manifest).join("src/suite/snapshots/equals.snap")
+        );
+    }
+}
@@ -1,20 +1,230 @@
-use std::panic::UnwindSafe;
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::future::Future;
+use std::panic::{AssertUnwindSafe, Location, RefUnwindSafe, UnwindSafe};
 use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use std::{io, panic};
 
+use crate::approx::{Approx, Tolerance};
+use crate::command::Command;
+use crate::executor::{self, AsyncHook};
+use crate::filter::Filter;
+use crate::junit::JunitReporter;
+use crate::matcher::{self, Matcher};
 use crate::output::{
-    ASSERT_LENGTH_EQUALS, ASSERT_LENGTH_UN0EQUALS, ASSERT_NOT_PANIC, ASSERT_PANIC, IS_CONTAINS,
-    IS_EQUALS, IS_EXISTS, IS_INFERIOR, IS_KO, IS_NOT_CONTAINS, IS_NOT_EXISTS, IS_OK, IS_SUPERIOR,
-    IS_UNEQUALS, THEORY_IS_FALSE, THEORY_IS_TRUE,
+    ASSERT_LENGTH_EQUALS, ASSERT_LENGTH_UN0EQUALS, ASSERT_NOT_PANIC, ASSERT_PANIC,
+    IS_APPROX_EQUALS, IS_CONTAINS, IS_EQUALS, IS_EXISTS, IS_INFERIOR, IS_KO, IS_NOT_APPROX_EQUALS,
+    IS_NOT_CONTAINS, IS_NOT_EXISTS, IS_OK, IS_OUTPUT, IS_SNAPSHOT_CHANGED, IS_SNAPSHOT_MATCH,
+    IS_SNAPSHOT_NEW, IS_SUPERIOR, IS_TEARDOWN_FAILED, IS_UNEQUALS, THEORY_IS_FALSE, THEORY_IS_TRUE,
 };
-use crate::run;
+use crate::parallel;
+use crate::property::{self, Config, Generator};
+use crate::report::{Outcome, Record, Report};
+use crate::reporter::{self, Reporter};
+use crate::snapshot::{self, Snapshot};
+
+///
+/// # The way a group is run
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    /// Run the group
+    Run,
+    /// Skip the group
+    Skip,
+    /// Run the group, its failures are recorded as todo
+    Todo,
+    /// Run the group and skip its siblings without the marker
+    Only,
+    /// Run the group on a worker thread with its parallel siblings
+    Parallel,
+}
+
+///
+/// # A declared group
+///
+#[derive(Clone)]
+struct Pending {
+    title: String,
+    description: String,
+    tags: Vec<String>,
+    mode: Mode,
+    callback: Group,
+}
+
+///
+/// # An event of a parallel group
+///
+enum Event {
+    /// A group is started with its title, its description and its depth
+    GroupStarted(String, String, usize),
+    /// A theory is started with its title, its description and its depth
+    TheoryStarted(String, String, usize),
+    /// An assertion is recorded at a depth
+    Assertion(Record, usize),
+    /// A hook is executed
+    HookExecuted(String),
+    /// A group is finished with its title and its depth
+    GroupFinished(String, usize),
+}
+
+///
+/// # A reporter keeping the events of a worker
+///
+#[derive(Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<Event>>>);
+
+impl Buffer {
+    ///
+    /// # Take the kept events
+    ///
+    fn take(&self) -> Vec<Event> {
+        self.0
+            .lock()
+            .map(|mut events| std::mem::take(&mut *events))
+            .unwrap_or_default()
+    }
+
+    ///
+    /// # Keep an event
+    ///
+    /// - `event` The event
+    ///
+    fn keep(&self, event: Event) {
+        if let Ok(mut events) = self.0.lock() {
+            events.push(event);
+        }
+    }
+}
+
+impl Reporter for Buffer {
+    fn group_started(&mut self, title: &str, description: &str, depth: usize) {
+        self.keep(Event::GroupStarted(
+            title.to_string(),
+            description.to_string(),
+            depth,
+        ));
+    }
+
+    fn theory_started(&mut self, title: &str, description: &str, depth: usize) {
+        self.keep(Event::TheoryStarted(
+            title.to_string(),
+            description.to_string(),
+            depth,
+        ));
+    }
+
+    fn assertion(&mut self, record: &Record, depth: usize) {
+        self.keep(Event::Assertion(record.clone(), depth));
+    }
+
+    fn hook_executed(&mut self, name: &str) {
+        self.keep(Event::HookExecuted(name.to_string()));
+    }
+
+    fn group_finished(&mut self, title: &str, depth: usize) {
+        self.keep(Event::GroupFinished(title.to_string(), depth));
+    }
+}
+
+///
+/// # A hook run around the tests
+///
+pub type Hook = Arc<dyn Fn() + Send + Sync + RefUnwindSafe>;
+
+///
+/// # The tests of a group
+///
+type Group = Arc<dyn Fn(Suite) -> Suite + Send + Sync + RefUnwindSafe>;
+
+///
+/// # The reporters shared by a suite and its clones
+///
+type Reporters = Arc<Mutex<Vec<Box<dyn Reporter>>>>;
+
+///
+/// # The after all hooks of the running groups, the innermost last
+///
+type Teardown = Arc<Mutex<Vec<Vec<Hook>>>>;
+
 ///
 /// # Represent a test suite
 ///
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct Suite {
-    before_each: Option<fn()>,
-    after_each: Option<fn()>,
+    before_each: Vec<Hook>,
+    after_each: Vec<Hook>,
+    teardown: Teardown,
+    group: String,
+    report: Report,
+    reporters: Reporters,
+    depth: usize,
+    finished: Option<Result<(), String>>,
+    values: Option<(String, String)>,
+    clock: Option<Instant>,
+    before_each_async: Option<AsyncHook>,
+    after_each_async: Option<AsyncHook>,
+    timeout: Option<Duration>,
+    soft: bool,
+    filter: Filter,
+    skipping: bool,
+    todo: bool,
+    pending: Option<Vec<Pending>>,
+}
+
+///
+/// # The error returned at the end of a suite
+///
+#[derive(Debug)]
+pub enum SuiteError {
+    /// A report can't be written
+    Io(io::Error),
+    /// The failed tests of a soft suite
+    Failures(Vec<Record>),
+}
+
+impl Display for SuiteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to write a report: {e}"),
+            Self::Failures(failures) => {
+                write!(f, "{} failure(s)", failures.len())?;
+                for failure in failures {
+                    write!(f, "\n  {}: {}", failure.group, failure.message)?;
+                    if let Some(at) = failure.at() {
+                        write!(f, " at {at}")?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for SuiteError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Failures(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for SuiteError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<SuiteError> for io::Error {
+    fn from(e: SuiteError) -> Self {
+        match e {
+            SuiteError::Io(e) => e,
+            failures => Self::other(failures.to_string()),
+        }
+    }
 }
 
 impl Suite {
@@ -27,8 +237,24 @@ impl Suite {
     #[must_use]
     pub fn new(before_each: Option<fn()>, after_each: Option<fn()>) -> Self {
         Self {
-            before_each,
-            after_each,
+            before_each: before_each.map(shared).into_iter().collect(),
+            after_each: after_each.map(shared).into_iter().collect(),
+            teardown: Arc::new(Mutex::new(vec![Vec::new()])),
+            group: String::new(),
+            report: Report::default(),
+            reporters: Arc::new(Mutex::new(reporter::suite())),
+            depth: 0,
+            finished: None,
+            values: None,
+            clock: None,
+            before_each_async: None,
+            after_each_async: None,
+            timeout: None,
+            soft: false,
+            filter: Filter::from_env(),
+            skipping: false,
+            todo: false,
+            pending: None,
         }
     }
     ///
@@ -43,278 +269,1499 @@ impl Suite {
     /// if test fail
     ///
     #[must_use]
+    #[track_caller]
     pub fn run(self, test: bool, success: &str, error: &str) -> Self {
-        let after = self.after_each;
-        let before = self.before_each;
-        run!(test, success, error, before, after);
+        self.run_as("run", test, success, error)
+    }
+
+    ///
+    /// # Run a test and record it
+    ///
+    /// - `kind` The assertion kind
+    /// - `test` The test
+    /// - `success` The success message
+    /// - `error` The error message
+    ///
+    #[track_caller]
+    fn run_as(self, kind: &str, test: bool, success: &str, error: &str) -> Self {
+        if self.skipping {
+            return self.unchecked(kind, success, Outcome::Skipped);
+        }
+        if self.todo && !test {
+            return self.unchecked(kind, error, Outcome::Todo);
+        }
+        self.check(kind, test, success, error)
+    }
+
+    ///
+    /// # Run the hooks around a test and record it
+    ///
+    /// - `kind` The assertion kind
+    /// - `test` The test
+    /// - `success` The success message
+    /// - `error` The error message
+    ///
+    /// # Panics
+    ///
+    /// On failure outside of the soft mode
+    ///
+    #[track_caller]
+    fn check(mut self, kind: &str, test: bool, success: &str, error: &str) -> Self {
+        let take: u128 = self
+            .clock
+            .take()
+            .unwrap_or_else(Instant::now)
+            .elapsed()
+            .as_nanos();
+        self.hook("before_each", None, self.before_each_async);
+        for hook in &self.before_each {
+            self.hook("before_each", Some(hook), None);
+        }
+        if test {
+            self.record(kind, success, Outcome::Passed, take);
+        } else {
+            self.record(kind, error, Outcome::Failed, take);
+        }
+        self.show();
+        let hooks: Vec<Hook> = self.after_each.clone();
+        let failure: Option<String> = self.tear_down("after_each", &hooks, self.after_each_async);
+        assert!(test || self.soft, "{error}");
+        self.raise(failure)
+    }
+
+    ///
+    /// # Run a callback, the next record is timed from its start
+    ///
+    /// - `callback` The tested callback
+    ///
+    fn timed<X>(&mut self, callback: impl FnOnce() -> X) -> X {
+        self.clock = Some(Instant::now());
+        callback()
+    }
+
+    ///
+    /// # Run teardown hooks and record their failures
+    ///
+    /// - `name` The hook name
+    /// - `hooks` The callbacks, run in the reverse order
+    /// - `hook_async` The async callback, run last
+    ///
+    fn tear_down(
+        &mut self,
+        name: &str,
+        hooks: &[Hook],
+        hook_async: Option<AsyncHook>,
+    ) -> Option<String> {
+        let records: Vec<Record> = tear_down(
+            &self.reporters,
+            (&self.group, self.depth),
+            name,
+            hooks,
+            hook_async,
+        );
+        let failure: Option<String> = records.first().map(|r| r.message.clone());
+        self.report.records.extend(records);
+        failure
+    }
+
+    ///
+    /// # Raise a teardown failure outside of the soft mode
+    ///
+    /// - `failure` The teardown failure
+    ///
+    /// # Panics
+    ///
+    /// On teardown failure outside of the soft mode
+    ///
+    fn raise(self, failure: Option<String>) -> Self {
+        if let Some(failure) = failure {
+            assert!(self.soft, "{failure}");
+        }
         self
     }
 
     ///
-    /// # End of the test suite
+    /// # Run a hook and notify the reporters
     ///
-    /// # Errors
+    /// - `name` The hook name
+    /// - `hook` The callback
+    /// - `hook_async` The async callback
     ///
-    pub fn end(&mut self) -> io::Result<()> {
-        Ok(())
+    fn hook(&self, name: &str, hook: Option<&Hook>, hook_async: Option<AsyncHook>) {
+        if hook.is_none() && hook_async.is_none() {
+            return;
+        }
+        executor::hook(hook_async);
+        if let Some(h) = hook {
+            h();
+        }
+        self.notify(|r| r.hook_executed(name));
     }
+
     ///
-    /// # Check equality
+    /// # Send an event to all the reporters
     ///
-    /// - `actual`      The actual value
-    /// - `expected`    The expected value
-    #[must_use]
-    pub fn eq<X: PartialEq>(self, actual: &X, expected: &X) -> Self {
-        self.run(actual.eq(expected), IS_EQUALS, IS_UNEQUALS)
+    /// - `event` The event
+    ///
+    fn notify(&self, event: impl Fn(&mut dyn Reporter)) {
+        notify(&self.reporters, event);
     }
+
     ///
-    ///  - `a` The result to check if match Ok
+    /// # Record a skipped or a todo test
     ///
-    #[must_use]
-    pub fn ok<X, Y>(self, a: &Result<X, Y>) -> Self {
-        self.run(a.is_ok(), IS_OK, IS_KO)
+    /// - `kind` The assertion kind
+    /// - `message` The output message
+    /// - `outcome` The assertion outcome
+    ///
+    #[track_caller]
+    fn unchecked(mut self, kind: &str, message: &str, outcome: Outcome) -> Self {
+        self.clock = None;
+        self.record(kind, message, outcome, 0);
+        self.show();
+        self
     }
 
     ///
-    /// # Check if a callback panic
+    /// # Send the last record to the reporters
     ///
-    /// - `c` The callback to check
+    fn show(&mut self) {
+        if let Some(record) = self.report.records.last() {
+            self.notify(|r| r.assertion(record, self.depth));
+        }
+    }
+
+    ///
+    /// # Send an event of a parallel group to the reporters
+    ///
+    /// The records of the assertions are appended to the report.
+    ///
+    /// - `event` The event
+    ///
+    fn replay(mut self, event: Event) -> Self {
+        match event {
+            Event::GroupStarted(title, description, depth) => {
+                self.notify(|r| r.group_started(&title, &description, depth));
+            }
+            Event::TheoryStarted(title, description, depth) => {
+                self.notify(|r| r.theory_started(&title, &description, depth));
+            }
+            Event::Assertion(record, depth) => {
+                self.notify(|r| r.assertion(&record, depth));
+                self.report.records.push(record);
+            }
+            Event::HookExecuted(name) => self.notify(|r| r.hook_executed(&name)),
+            Event::GroupFinished(title, depth) => self.notify(|r| r.group_finished(&title, depth)),
+        }
+        self
+    }
+
+    ///
+    /// # Attach the expected and the actual values to the next test
+    ///
+    /// - `expected` The expected value
+    /// - `actual` The actual value
+    ///
+    fn values(mut self, expected: &str, actual: &str) -> Self {
+        self.values = Some((expected.to_string(), actual.to_string()));
+        self
+    }
+
+    ///
+    /// # Append an assertion to the report
+    ///
+    /// - `kind` The assertion kind
+    /// - `message` The output message
+    /// - `outcome` The assertion outcome
+    /// - `take` The elapsed time in nanoseconds
+    ///
+    #[track_caller]
+    fn record(&mut self, kind: &str, message: &str, outcome: Outcome, take: u128) {
+        let mut record = Record::new(kind, message, outcome, take, &self.group);
+        if let Some((expected, actual)) = self.values.take() {
+            record = record.with_values(&expected, &actual);
+        }
+        record.location = Some(Location::caller());
+        self.report.records.push(record);
+    }
+
+    ///
+    /// # Add a hook run before each test of the current group
+    ///
+    /// The hooks of the parent groups run first, the hook is removed at the end of the group.
+    ///
+    /// - `hook` The callback
     ///
     #[must_use]
-    pub fn panic(self, c: impl FnOnce() + UnwindSafe) -> Self {
-        let result = panic::catch_unwind(c).is_ok();
-        self.run(result.eq(&false), ASSERT_PANIC, ASSERT_NOT_PANIC)
+    pub fn before_each(mut self, hook: impl Fn() + Send + Sync + RefUnwindSafe + 'static) -> Self {
+        self.before_each.push(Arc::new(hook));
+        self
     }
 
     ///
-    /// # Check if a callback don't panic
+    /// # Add a hook run after each test of the current group
     ///
-    /// - `c` The callback to check
+    /// The hooks of the parent groups run last, the hook is removed at the end of the group.
+    ///
+    /// - `hook` The callback
     ///
     #[must_use]
-    pub fn not_panic(self, c: impl FnOnce() + UnwindSafe) -> Self {
-        let result = panic::catch_unwind(c);
-        self.run(result.is_ok(), ASSERT_NOT_PANIC, ASSERT_PANIC)
+    pub fn after_each(mut self, hook: impl Fn() + Send + Sync + RefUnwindSafe + 'static) -> Self {
+        self.after_each.push(Arc::new(hook));
+        self
     }
 
     ///
-    /// - `a` The data to check if X match Err
+    /// # Run a hook before the next tests of the current group
+    ///
+    /// - `hook` The callback
     ///
     #[must_use]
-    pub fn ko<X, Y>(self, a: &Result<X, Y>) -> Self {
-        self.run(a.is_err(), IS_KO, IS_OK)
+    pub fn before_all(self, hook: impl Fn() + Send + Sync + RefUnwindSafe + 'static) -> Self {
+        self.hook("before_all", Some(&(Arc::new(hook) as Hook)), None);
+        self
     }
+
     ///
-    /// # Check the len
+    /// # Add a hook run at the end of the current group
     ///
-    /// - `actual` The actual len
-    /// - `expected`The expected len
+    /// The hooks run in the reverse order of their declaration.
+    ///
+    /// - `hook` The callback
     ///
     #[must_use]
-    pub fn len<X: ExactSizeIterator>(self, actual: &X, expected: &usize) -> Self {
-        self.run(
-            actual.len().eq(expected),
-            ASSERT_LENGTH_EQUALS,
-            ASSERT_LENGTH_UN0EQUALS,
-        )
+    pub fn after_all(self, hook: impl Fn() + Send + Sync + RefUnwindSafe + 'static) -> Self {
+        if let Ok(mut teardown) = self.teardown.lock() {
+            match teardown.last_mut() {
+                Some(scope) => scope.push(Arc::new(hook)),
+                None => teardown.push(vec![Arc::new(hook)]),
+            }
+        }
+        self
     }
 
     ///
-    /// # Check inequality
+    /// # Write the junit report in a directory at the end
     ///
-    /// - `actual`      The actual value
-    /// - `expected`    The expected value
+    /// - `directory` The junit output directory
     ///
     #[must_use]
-    pub fn ne<X: PartialEq>(self, actual: &X, expected: &X) -> Self {
-        self.run(actual.ne(expected), IS_UNEQUALS, IS_EQUALS)
+    pub fn junit(self, directory: &str) -> Self {
+        self.reporter(JunitReporter::new(Some(directory)))
     }
+
     ///
-    /// # Check if actual is greater than expected
+    /// # Add a reporter
     ///
-    /// - `actual` The actual value
-    /// - `expected` The expected value
+    /// A reporter added in a running suite is started with it.
+    ///
+    /// - `reporter` The reporter receiving the events of the suite
     ///
     #[must_use]
-    pub fn gt<X: PartialOrd>(self, actual: &X, expected: &X) -> Self {
-        self.run(actual.gt(expected), IS_SUPERIOR, IS_INFERIOR)
+    pub fn reporter(self, mut reporter: impl Reporter + 'static) -> Self {
+        if !self.report.title.is_empty() {
+            reporter.suite_started(&self.report.title, &self.report.description);
+        }
+        if let Ok(mut reporters) = self.reporters.lock() {
+            reporters.push(Box::new(reporter));
+        }
+        self
     }
+
     ///
-    /// # Check if actual is greater or equal than expected
+    /// # Fail the async tests and the `in_time` callbacks not completed before a timeout
     ///
-    /// - `actual` The actual value
-    /// - `expected` The expected value
+    /// - `timeout` The maximum duration of a future or a callback
     ///
     #[must_use]
-    pub fn ge<X: PartialOrd>(self, actual: &X, expected: &X) -> Self {
-        self.run(actual.ge(expected), IS_SUPERIOR, IS_INFERIOR)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
     }
 
     ///
-    /// # Check if actual is containing expected
+    /// # Run a future, compare its output and record it
     ///
-    /// - `actual` The actual value
-    /// - `expected` The expected value
+    /// - `kind` The assertion kind
+    /// - `future` The future to run
+    /// - `expected` The expected output
+    /// - `success` The success message
+    /// - `error` The error message
+    ///
+    #[track_caller]
+    fn run_async<X: PartialEq, F: Future<Output = X>>(
+        mut self,
+        kind: &str,
+        future: F,
+        expected: &X,
+        success: &str,
+        error: &str,
+    ) -> Self {
+        if self.skipping {
+            return self.unchecked(kind, success, Outcome::Skipped);
+        }
+        let timeout: Option<Duration> = self.timeout;
+        match self.timed(|| executor::block_on_timeout(future, timeout)) {
+            Some(actual) => self.run_as(kind, actual.eq(expected), success, error),
+            None => {
+                let error: String = executor::timed_out(self.timeout.unwrap_or_default());
+                self.run_as(kind, false, success, &error)
+            }
+        }
+    }
+
+    ///
+    /// # Run only the groups selected by a filter
+    ///
+    /// The filter is read from `UNIT_TESTING_FILTER` by default, the tests of the other groups are skipped.
+    ///
+    /// - `filter` The filter
     ///
     #[must_use]
-    pub fn str_contains(self, actual: &str, expected: &str) -> Self {
-        self.run(actual.contains(expected), IS_CONTAINS, IS_NOT_CONTAINS)
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.skipping = filter.is_restrictive() && !filter.includes(&self.group);
+        self.filter = filter;
+        self
     }
 
     ///
-    /// # Check if an actual path matches the expected value
+    /// # Record the failures instead of panicking on the first one
     ///
-    /// - `actual` The actual value
-    /// - `expected` The expected value
+    /// The failures are returned by `end`.
     ///
     #[must_use]
-    pub fn path_exists(self, actual: &str, expected: bool) -> Self {
-        self.run(Path::new(actual).exists().eq(&expected), IS_OK, IS_KO)
+    pub fn soft(mut self) -> Self {
+        self.soft = true;
+        self
     }
 
     ///
-    /// # Check if an actual path exists
+    /// # End of the test suite
     ///
-    /// - `actual` The actual path
+    /// # Errors
+    ///
+    /// On report write failure, or with all the failures in soft mode
+    ///
+    pub fn end(&mut self) -> Result<(), SuiteError> {
+        self.finish();
+        if let Some(Err(e)) = &self.finished {
+            return Err(SuiteError::Io(io::Error::other(e.clone())));
+        }
+        let failures: Vec<Record> = self
+            .report
+            .records
+            .iter()
+            .filter(|r| r.failed())
+            .cloned()
+            .collect();
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(SuiteError::Failures(failures))
+        }
+    }
+
+    ///
+    /// # End of the test suite and return the recorded assertions
     ///
     #[must_use]
-    pub fn exists(self, actual: &str) -> Self {
-        self.run(Path::new(actual).exists(), IS_EXISTS, IS_NOT_EXISTS)
+    pub fn end_with_report(&mut self) -> Report {
+        self.finish();
+        self.report.clone()
     }
 
     ///
-    /// # Check if actual is not containing expected
+    /// # Finish the reporters once and keep the result
     ///
-    /// - `actual` The actual value
-    /// - `expected` The expected value
+    fn finish(&mut self) {
+        if self.finished.is_some() {
+            return;
+        }
+        let result: io::Result<()> = match self.reporters.lock() {
+            Ok(mut reporters) => reporter::finish(&mut reporters, &self.report),
+            Err(_) => Ok(()),
+        };
+        self.finished = Some(result.map_err(|e| e.to_string()));
+    }
+
+    ///
+    /// # Check a value with a matcher
+    ///
+    /// - `actual`      The actual value
+    /// - `matcher`     The matcher describing the expected value
     ///
     #[must_use]
-    pub fn str_not_contains(self, actual: &str, expected: &str) -> Self {
-        self.run(
-            actual.contains(expected).eq(&false),
-            IS_NOT_CONTAINS,
-            IS_CONTAINS,
-        )
+    #[track_caller]
+    pub fn expect<X: ?Sized, M: Matcher<X>>(self, actual: &X, matcher: M) -> Self {
+        let (test, success, error): (bool, String, String) = matcher::check(actual, &matcher);
+        self.run_as("expect", test, &success, &error)
     }
 
     ///
-    /// # Check if actual is lower or equal than expected
+    /// # Check equality
     ///
-    /// - `actual` The actual value
-    /// - `expected` The expected value
+    /// - `actual`      The actual value
+    /// - `expected`    The expected value
+    #[must_use]
+    #[track_caller]
+    pub fn eq<X: PartialEq + Debug>(self, actual: &X, expected: &X) -> Self {
+        let (test, success, error): (bool, String, String) =
+            matcher::check(&actual, &matcher::eq(expected));
+        self.run_as("eq", test, &success, &error)
+    }
+
+    ///
+    /// # Check if floats, or slices of floats, are approximately equals
+    ///
+    /// - `actual`      The actual value
+    /// - `expected`    The expected value
+    /// - `tolerance`   The accepted difference
     ///
     #[must_use]
-    pub fn le<X: PartialOrd>(self, actual: &X, expected: &X) -> Self {
-        self.run(actual.le(expected), IS_INFERIOR, IS_SUPERIOR)
+    #[track_caller]
+    pub fn approx_eq<X: Approx + Debug + ?Sized>(
+        self,
+        actual: &X,
+        expected: &X,
+        tolerance: Tolerance,
+    ) -> Self {
+        let result: Result<(), String> = actual.approx(expected, tolerance);
+        let error: String = format!(
+            "{IS_NOT_APPROX_EQUALS}: {}",
+            result.as_ref().err().map_or("", String::as_str)
+        );
+        self.values(&format!("{expected:#?}"), &format!("{actual:#?}"))
+            .run_as("approx_eq", result.is_ok(), IS_APPROX_EQUALS, &error)
     }
+
     ///
-    /// # Check if actual is lower than expected
+    /// # Check the equality of a future output
     ///
-    /// - `actual` The actual value
-    /// - `expected` The expected value
+    /// - `actual`      The future to run
+    /// - `expected`    The expected value
     ///
     #[must_use]
-    pub fn lt<X: PartialOrd>(self, actual: &X, expected: &X) -> Self {
-        self.run(actual.lt(expected), IS_INFERIOR, IS_SUPERIOR)
+    #[track_caller]
+    pub fn eq_async<X: PartialEq, F: Future<Output = X>>(self, actual: F, expected: &X) -> Self {
+        self.run_async("eq_async", actual, expected, IS_EQUALS, IS_UNEQUALS)
+    }
+
+    ///
+    ///  - `a` The result to check if match Ok
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn ok<X, Y>(self, a: &Result<X, Y>) -> Self {
+        self.run_as("ok", a.is_ok(), IS_OK, IS_KO)
+    }
+
+    ///
+    /// # Check if a callback panic
+    ///
+    /// - `c` The callback to check
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn panic(mut self, c: impl FnOnce() + UnwindSafe) -> Self {
+        let result = self.timed(|| panic::catch_unwind(c)).is_ok();
+        self.run_as("panic", result.eq(&false), ASSERT_PANIC, ASSERT_NOT_PANIC)
+    }
+
+    ///
+    /// # Check if a future panic
+    ///
+    /// - `f` The future to check
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn panic_async<F: Future>(mut self, f: F) -> Self {
+        match self.unwind(f) {
+            Some(panicked) => self.run_as("panic_async", panicked, ASSERT_PANIC, ASSERT_NOT_PANIC),
+            None => {
+                let error: String = executor::timed_out(self.timeout.unwrap_or_default());
+                self.run_as("panic_async", false, ASSERT_PANIC, &error)
+            }
+        }
+    }
+
+    ///
+    /// # Check if a future don't panic
+    ///
+    /// - `f` The future to check
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn not_panic_async<F: Future>(mut self, f: F) -> Self {
+        match self.unwind(f) {
+            Some(panicked) => self.run_as(
+                "not_panic_async",
+                panicked.eq(&false),
+                ASSERT_NOT_PANIC,
+                ASSERT_PANIC,
+            ),
+            None => {
+                let error: String = executor::timed_out(self.timeout.unwrap_or_default());
+                self.run_as("not_panic_async", false, ASSERT_NOT_PANIC, &error)
+            }
+        }
+    }
+
+    ///
+    /// # Check if a callback return true before a timeout
+    ///
+    /// The callback runs on a worker thread, the test fails if it panics or times out.
+    ///
+    /// - `timeout` The maximum duration
+    /// - `callback` The callback to check
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn within(
+        self,
+        timeout: Duration,
+        callback: impl FnOnce() -> bool + Send + 'static,
+    ) -> Self {
+        self.run_within("within", Some(timeout), callback)
+    }
+
+    ///
+    /// # Check if a callback return true before the suite timeout
+    ///
+    /// The callback runs on a worker thread, the test fails if it panics or times out.
+    ///
+    /// - `callback` The callback to check
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn in_time(self, callback: impl FnOnce() -> bool + Send + 'static) -> Self {
+        let timeout: Option<Duration> = self.timeout;
+        self.run_within("in_time", timeout, callback)
+    }
+
+    ///
+    /// # Run a callback on a worker thread and record it
+    ///
+    /// - `kind` The assertion kind
+    /// - `timeout` The maximum duration, unlimited if `None`
+    /// - `callback` The callback to check
+    ///
+    #[track_caller]
+    fn run_within(
+        mut self,
+        kind: &str,
+        timeout: Option<Duration>,
+        callback: impl FnOnce() -> bool + Send + 'static,
+    ) -> Self {
+        if self.skipping {
+            return self.unchecked(kind, IS_OK, Outcome::Skipped);
+        }
+        match self.timed(|| executor::within(timeout, callback)) {
+            Some(Ok(test)) => self.run_as(kind, test, IS_OK, IS_KO),
+            Some(Err(_)) => self.run_as(kind, false, IS_OK, ASSERT_PANIC),
+            None => {
+                let error: String = executor::timed_out(timeout.unwrap_or_default());
+                self.run_as(kind, false, IS_OK, &error)
+            }
+        }
+    }
+
+    ///
+    /// # Run a future and check if it panicked
+    ///
+    /// Return `None` if the future is not completed before the timeout.
+    ///
+    /// - `f` The future to run
+    ///
+    fn unwind<F: Future>(&mut self, f: F) -> Option<bool> {
+        if self.skipping {
+            return Some(false);
+        }
+        let timeout: Option<Duration> = self.timeout;
+        match self.timed(|| {
+            panic::catch_unwind(AssertUnwindSafe(|| executor::block_on_timeout(f, timeout)))
+        }) {
+            Ok(output) => output.map(|_| false),
+            Err(_) => Some(true),
+        }
+    }
+
+    ///
+    /// # Check if a callback don't panic
+    ///
+    /// - `c` The callback to check
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn not_panic(mut self, c: impl FnOnce() + UnwindSafe) -> Self {
+        let result = self.timed(|| panic::catch_unwind(c));
+        self.run_as("not_panic", result.is_ok(), ASSERT_NOT_PANIC, ASSERT_PANIC)
+    }
+
+    ///
+    /// - `a` The data to check if X match Err
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn ko<X, Y>(self, a: &Result<X, Y>) -> Self {
+        self.run_as("ko", a.is_err(), IS_KO, IS_OK)
+    }
+    ///
+    /// # Check the len
+    ///
+    /// - `actual` The actual len
+    /// - `expected`The expected len
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn len<X: ExactSizeIterator>(self, actual: &X, expected: &usize) -> Self {
+        self.values(&expected.to_string(), &actual.len().to_string())
+            .run_as(
+                "len",
+                actual.len().eq(expected),
+                ASSERT_LENGTH_EQUALS,
+                ASSERT_LENGTH_UN0EQUALS,
+            )
+    }
+
+    ///
+    /// # Check equality and show a diff of the debug outputs on failure
+    ///
+    /// - `actual`      The actual value
+    /// - `expected`    The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn eq_debug<X: PartialEq + Debug>(self, actual: &X, expected: &X) -> Self {
+        self.values(&format!("{expected:#?}"), &format!("{actual:#?}"))
+            .run_as("eq_debug", actual.eq(expected), IS_EQUALS, IS_UNEQUALS)
+    }
+
+    ///
+    /// # Check string equality and show a diff on failure
+    ///
+    /// - `actual`      The actual value
+    /// - `expected`    The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn str_eq(self, actual: &str, expected: &str) -> Self {
+        self.values(expected, actual)
+            .run_as("str_eq", actual.eq(expected), IS_EQUALS, IS_UNEQUALS)
+    }
+
+    ///
+    /// # Check if a value matches its snapshot
+    ///
+    /// The snapshot is stored in a `snapshots` directory next to the test file,
+    /// a `.snap.new` file is written for review on a new or changed snapshot.
+    ///
+    /// - `name`    The snapshot name
+    /// - `value`   The actual value
+    ///
+    /// # Panics
+    ///
+    /// On snapshot read or write failure
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn snapshot(self, name: &str, value: &str) -> Self {
+        if self.skipping {
+            return self.unchecked("snapshot", IS_SNAPSHOT_MATCH, Outcome::Skipped);
+        }
+        match snapshot::check(Location::caller().file(), name, value)
+            .unwrap_or_else(|e| panic!("The snapshot {name} can't be checked: {e}"))
+        {
+            Snapshot::Changed(expected) => self.values(&expected, value).run_as(
+                "snapshot",
+                false,
+                IS_SNAPSHOT_MATCH,
+                IS_SNAPSHOT_CHANGED,
+            ),
+            Snapshot::New => self.run_as("snapshot", false, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_NEW),
+            Snapshot::Matched | Snapshot::Accepted => {
+                self.run_as("snapshot", true, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_CHANGED)
+            }
+        }
+    }
+
+    ///
+    /// # Run a command and check its expectations
+    ///
+    /// Each expectation is recorded, a command that can't be spawned is a failure.
+    ///
+    /// - `command` The command built with `cmd`
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn command(mut self, command: &Command) -> Self {
+        if self.skipping {
+            return self.unchecked("command", IS_OUTPUT, Outcome::Skipped);
+        }
+        let file: &str = Location::caller().file();
+        self.timed(|| command.verify(file))
+            .into_iter()
+            .fold(self, |suite, check| {
+                let suite: Self = match &check.values {
+                    Some((expected, actual)) => suite.values(expected, actual),
+                    None => suite,
+                };
+                suite.run_as(&check.kind, check.passed, &check.success, &check.error)
+            })
+    }
+
+    ///
+    /// # Check inequality and record the debug outputs
+    ///
+    /// - `actual`      The actual value
+    /// - `expected`    The unexpected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn ne_debug<X: PartialEq + Debug>(self, actual: &X, expected: &X) -> Self {
+        self.values(&format!("{expected:#?}"), &format!("{actual:#?}"))
+            .run_as("ne_debug", actual.ne(expected), IS_UNEQUALS, IS_EQUALS)
+    }
+
+    ///
+    /// # Check inequality
+    ///
+    /// - `actual`      The actual value
+    /// - `expected`    The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn ne<X: PartialEq>(self, actual: &X, expected: &X) -> Self {
+        self.run_as("ne", actual.ne(expected), IS_UNEQUALS, IS_EQUALS)
+    }
+    ///
+    /// # Check if actual is greater than expected
+    ///
+    /// - `actual` The actual value
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn gt<X: PartialOrd>(self, actual: &X, expected: &X) -> Self {
+        self.run_as("gt", actual.gt(expected), IS_SUPERIOR, IS_INFERIOR)
+    }
+    ///
+    /// # Check if actual is greater or equal than expected
+    ///
+    /// - `actual` The actual value
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn ge<X: PartialOrd>(self, actual: &X, expected: &X) -> Self {
+        self.run_as("ge", actual.ge(expected), IS_SUPERIOR, IS_INFERIOR)
+    }
+
+    ///
+    /// # Check if actual is containing expected
+    ///
+    /// - `actual` The actual value
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn str_contains(self, actual: &str, expected: &str) -> Self {
+        let (test, success, error): (bool, String, String) =
+            matcher::check(actual, &matcher::str_contains(expected));
+        self.values(expected, actual)
+            .run_as("str_contains", test, &success, &error)
+    }
+
+    ///
+    /// # Check if an actual path matches the expected value
+    ///
+    /// - `actual` The actual value
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn path_exists(self, actual: &str, expected: bool) -> Self {
+        self.run_as(
+            "path_exists",
+            Path::new(actual).exists().eq(&expected),
+            IS_OK,
+            IS_KO,
+        )
+    }
+
+    ///
+    /// # Check if an actual path exists
+    ///
+    /// - `actual` The actual path
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn exists(self, actual: &str) -> Self {
+        self.run_as(
+            "exists",
+            Path::new(actual).exists(),
+            IS_EXISTS,
+            IS_NOT_EXISTS,
+        )
+    }
+
+    ///
+    /// # Check if actual is not containing expected
+    ///
+    /// - `actual` The actual value
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn str_not_contains(self, actual: &str, expected: &str) -> Self {
+        self.values(expected, actual).run_as(
+            "str_not_contains",
+            actual.contains(expected).eq(&false),
+            IS_NOT_CONTAINS,
+            IS_CONTAINS,
+        )
+    }
+
+    ///
+    /// # Check if actual is lower or equal than expected
+    ///
+    /// - `actual` The actual value
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn le<X: PartialOrd>(self, actual: &X, expected: &X) -> Self {
+        self.run_as("le", actual.le(expected), IS_INFERIOR, IS_SUPERIOR)
+    }
+    ///
+    /// # Check if actual is lower than expected
+    ///
+    /// - `actual` The actual value
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn lt<X: PartialOrd>(self, actual: &X, expected: &X) -> Self {
+        self.run_as("lt", actual.lt(expected), IS_INFERIOR, IS_SUPERIOR)
+    }
+
+    ///
+    /// # Check if the callback exit code match the expected exit code
+    ///
+    /// - `actual` The callback to check
+    /// - `expected` The expected code
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn response<X: PartialEq>(
+        self,
+        title: &str,
+        description: &str,
+        c: &dyn Fn(X) -> X,
+        x: X,
+        expected: &X,
+    ) -> Self {
+        let mut suite: Self = self.title(title, description);
+        let actual: X = suite.timed(|| c(x));
+        suite.run_as("response", actual.eq(expected), IS_EQUALS, IS_UNEQUALS)
+    }
+
+    ///
+    /// # Check if a theorem is equal to expected value
+    ///
+    /// - `c` The theorem callback
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn theorem<X: PartialEq>(
+        self,
+        title: &str,
+        description: &str,
+        c: &dyn Fn() -> X,
+        expected: &X,
+    ) -> Self {
+        let mut suite: Self = self.sub_title(title, description);
+        let actual: X = suite.timed(c);
+        suite.run_as("theorem", actual.eq(expected), IS_OK, IS_KO)
+    }
+
+    ///
+    /// # Check if a theorem is equal to expected value
+    ///
+    /// - `c` The theorem callback
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn theory<X: PartialEq>(
+        self,
+        title: &str,
+        description: &str,
+        callback: &X,
+        expected: &X,
+    ) -> Self {
+        self.sub_title(title, description).run_as(
+            "theory",
+            callback.eq(expected),
+            THEORY_IS_TRUE,
+            THEORY_IS_FALSE,
+        )
+    }
+
+    ///
+    /// # Check if the output of an async theory is equal to expected value
+    ///
+    /// - `future` The theory future
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn theory_async<X: PartialEq, F: Future<Output = X>>(
+        self,
+        title: &str,
+        description: &str,
+        future: F,
+        expected: &X,
+    ) -> Self {
+        self.sub_title(title, description).run_async(
+            "theory_async",
+            future,
+            expected,
+            THEORY_IS_TRUE,
+            THEORY_IS_FALSE,
+        )
+    }
+
+    ///
+    /// # Check a property against generated values
+    ///
+    /// The number of cases and the seed are read from `UNIT_TESTING_CASES` and `UNIT_TESTING_SEED`.
+    /// The first counterexample is shrunk and reported with the seed replaying it.
+    ///
+    /// - `generator` The values generator
+    /// - `property` The property to check
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn for_all<G: Generator>(
+        self,
+        title: &str,
+        description: &str,
+        generator: G,
+        property: &dyn Fn(G::Value) -> bool,
+    ) -> Self {
+        let mut suite: Self = self.sub_title(title, description);
+        if suite.skipping {
+            return suite.unchecked("for_all", THEORY_IS_TRUE, Outcome::Skipped);
+        }
+        match suite.timed(|| property::check(&Config::from_env(), &generator, property)) {
+            Ok(_) => suite.run_as("for_all", true, THEORY_IS_TRUE, THEORY_IS_FALSE),
+            Err(counterexample) => {
+                let error: String = counterexample.message(THEORY_IS_FALSE);
+                suite.run_as("for_all", false, THEORY_IS_TRUE, &error)
+            }
+        }
+    }
+
+    ///
+    /// # Check if a theorem is different to expected
+    ///
+    /// - `c` The theorem callback
+    /// - `expected` The expected value
+    ///
+    #[must_use]
+    #[track_caller]
+    pub fn chaos<X: PartialEq>(
+        self,
+        title: &str,
+        description: &str,
+        c: &dyn Fn() -> X,
+        expected: &X,
+    ) -> Self {
+        let mut suite: Self = self.title(title, description);
+        let actual: X = suite.timed(c);
+        suite.run_as(
+            "chaos",
+            actual.ne(expected),
+            THEORY_IS_TRUE,
+            THEORY_IS_FALSE,
+        )
+    }
+    fn title(mut self, title: &str, description: &str) -> Self {
+        self.notify(|r| r.theory_started(title, description, self.depth));
+        self.group = title.to_string();
+        self
+    }
+
+    fn sub_title(self, title: &str, description: &str) -> Self {
+        self.notify(|r| r.theory_started(title, description, self.depth));
+        self
+    }
+
+    ///
+    /// # Run a group of tests
+    ///
+    /// - `title` The group title
+    /// - `description` The group description
+    /// - `callback` The group tests
+    ///
+    #[must_use]
+    pub fn group(
+        self,
+        title: &str,
+        description: &str,
+        callback: impl Fn(Self) -> Self + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        self.declare(title, description, &[], Mode::Run, callback)
+    }
+
+    ///
+    /// # Run a tagged group of tests
+    ///
+    /// The tags are selected or excluded by the `@tag` and `!@tag` filter terms.
+    ///
+    /// - `title` The group title
+    /// - `description` The group description
+    /// - `tags` The group tags
+    /// - `callback` The group tests
+    ///
+    #[must_use]
+    pub fn group_tagged(
+        self,
+        title: &str,
+        description: &str,
+        tags: &[&str],
+        callback: impl Fn(Self) -> Self + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        self.declare(title, description, tags, Mode::Run, callback)
+    }
+
+    ///
+    /// # Skip a group of tests
+    ///
+    /// - `title` The group title
+    /// - `description` The group description
+    /// - `callback` The skipped tests
+    ///
+    #[must_use]
+    pub fn group_skip(
+        self,
+        title: &str,
+        description: &str,
+        callback: impl Fn(Self) -> Self + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        self.declare(title, description, &[], Mode::Skip, callback)
+    }
+
+    ///
+    /// # Run a group of unfinished tests
+    ///
+    /// The failures are recorded as todo and don't fail the suite.
+    ///
+    /// - `title` The group title
+    /// - `description` The group description
+    /// - `callback` The group tests
+    ///
+    #[must_use]
+    pub fn group_todo(
+        self,
+        title: &str,
+        description: &str,
+        callback: impl Fn(Self) -> Self + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        self.declare(title, description, &[], Mode::Todo, callback)
+    }
+
+    ///
+    /// # Run a group of tests and skip its siblings
+    ///
+    /// The siblings are the groups declared in the same `batch`.
+    ///
+    /// - `title` The group title
+    /// - `description` The group description
+    /// - `callback` The group tests
+    ///
+    #[must_use]
+    pub fn group_only(
+        self,
+        title: &str,
+        description: &str,
+        callback: impl Fn(Self) -> Self + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        self.declare(title, description, &[], Mode::Only, callback)
+    }
+
+    ///
+    /// # Run a group of tests on a worker thread
+    ///
+    /// The parallel groups declared next to each other in a `batch` run together on a pool of
+    /// `UNIT_TESTING_WORKERS` threads, their results are shown in the declaration order.
+    /// The hooks run on the workers.
+    ///
+    /// - `title` The group title
+    /// - `description` The group description
+    /// - `callback` The group tests
+    ///
+    #[must_use]
+    pub fn group_parallel(
+        self,
+        title: &str,
+        description: &str,
+        callback: impl Fn(Self) -> Self + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        self.declare(title, description, &[], Mode::Parallel, callback)
+    }
+
+    ///
+    /// # Declare sibling groups
+    ///
+    /// The groups declared in the callback run together at its end, in the declaration order.
+    /// A `group_only` skips the other groups of the batch,
+    /// the adjacent `group_parallel` run at the same time.
+    /// The tests of the callback outside of a group run at once.
+    ///
+    /// - `callback` The group declarations
+    ///
+    #[must_use]
+    pub fn batch(mut self, callback: impl FnOnce(Self) -> Self) -> Self {
+        let outer: Option<Vec<Pending>> = self.pending.replace(Vec::new());
+        let mut suite: Self = callback(self);
+        let batch: Vec<Pending> = std::mem::replace(&mut suite.pending, outer).unwrap_or_default();
+        suite.run_batch(batch)
+    }
+
+    ///
+    /// # Run a group, or add it to the running batch
+    ///
+    /// - `title` The group title
+    /// - `description` The group description
+    /// - `tags` The group tags
+    /// - `mode` The way the group is run
+    /// - `callback` The group tests
+    ///
+    fn declare(
+        mut self,
+        title: &str,
+        description: &str,
+        tags: &[&str],
+        mode: Mode,
+        callback: impl Fn(Self) -> Self + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        let group: Pending = Pending {
+            title: title.to_string(),
+            description: description.to_string(),
+            tags: tags.iter().map(ToString::to_string).collect(),
+            mode,
+            callback: Arc::new(callback),
+        };
+        match self.pending.as_mut() {
+            Some(pending) => {
+                pending.push(group);
+                self
+            }
+            None => self.run_batch(vec![group]),
+        }
+    }
+
+    ///
+    /// # Run the groups of a batch
+    ///
+    /// - `pending` The declared groups
+    ///
+    fn run_batch(mut self, pending: Vec<Pending>) -> Self {
+        let only: bool = pending.iter().any(|p| p.mode.eq(&Mode::Only));
+        let mut batch: Vec<Pending> = Vec::new();
+        for group in pending {
+            if group.mode.eq(&Mode::Parallel) && !only {
+                batch.push(group);
+                continue;
+            }
+            self = self.run_parallel(std::mem::take(&mut batch));
+            self = if only && group.mode.ne(&Mode::Only) {
+                self.unchecked("group", &group.title, Outcome::Skipped)
+            } else {
+                let callback: Group = Arc::clone(&group.callback);
+                self.run_group(&group, |s| callback(s))
+            };
+        }
+        self.run_parallel(batch)
+    }
+
+    ///
+    /// # Run declared groups on the workers and replay their events in order
+    ///
+    /// A panicking group is recorded as a failure in the soft mode.
+    ///
+    /// - `batch` The groups to run
+    ///
+    /// # Panics
+    ///
+    /// With the panic of a group outside of the soft mode
+    ///
+    fn run_parallel(mut self, batch: Vec<Pending>) -> Self {
+        if batch.is_empty() {
+            return self;
+        }
+        let mut worker: Self = self.clone();
+        worker.report = Report::default();
+        worker.teardown = Arc::new(Mutex::new(vec![Vec::new()]));
+        worker.values = None;
+        let buffers: Vec<Buffer> = batch.iter().map(|_| Buffer::default()).collect();
+        let jobs: Vec<(Self, Pending)> = batch
+            .iter()
+            .zip(&buffers)
+            .map(|(group, buffer)| {
+                let mut suite: Self = worker.clone();
+                suite.reporters = Arc::new(Mutex::new(vec![Box::new(buffer.clone())]));
+                (suite, group.clone())
+            })
+            .collect();
+        let results = parallel::run(jobs, &|(suite, group): (Self, Pending)| {
+            let callback: Group = Arc::clone(&group.callback);
+            let _ = suite.run_group(&group, |s| callback(s));
+        });
+        for ((group, buffer), result) in batch.iter().zip(&buffers).zip(results) {
+            self = buffer.take().into_iter().fold(self, Self::replay);
+            if let Err(cause) = result {
+                if !self.soft {
+                    panic::resume_unwind(cause);
+                }
+                let record = Record::new("group", ASSERT_PANIC, Outcome::Failed, 0, &group.title);
+                let depth: usize = self.depth + 1;
+                self = self.replay(Event::Assertion(record, depth));
+            }
+        }
+        self
+    }
+
+    ///
+    /// # Run a declared group
+    ///
+    /// - `pending` The group to run
+    /// - `body` The group tests
+    ///
+    fn run_group(mut self, pending: &Pending, body: impl FnOnce(Self) -> Self) -> Self {
+        let title: &str = pending.title.as_str();
+        let description: &str = pending.description.as_str();
+        let tags: Vec<&str> = pending.tags.iter().map(String::as_str).collect();
+        if pending.mode.eq(&Mode::Skip)
+            || self.filter.excludes(title)
+            || self.filter.excludes_tags(&tags)
+        {
+            return self.unchecked("group", title, Outcome::Skipped);
+        }
+        let skipping: bool = self.skipping;
+        let todo: bool = self.todo;
+        if skipping && (self.filter.includes(title) || self.filter.includes_tags(&tags)) {
+            self.skipping = false;
+        }
+        self.todo = todo || pending.mode.eq(&Mode::Todo);
+        let parent: String = self.group.clone();
+        let (before_each, after_each): (usize, usize) =
+            (self.before_each.len(), self.after_each.len());
+        let (reporters, teardown): (Reporters, Teardown) =
+            (Arc::clone(&self.reporters), Arc::clone(&self.teardown));
+        open(&teardown);
+        self.group = title.to_string();
+        self.depth += 1;
+        let depth: usize = self.depth;
+        self.notify(|r| r.group_started(title, description, depth));
+        let mut suite: Self = match panic::catch_unwind(AssertUnwindSafe(|| body(self))) {
+            Ok(suite) => suite,
+            Err(cause) => {
+                let hooks: Vec<Hook> = close(&teardown);
+                tear_down(&reporters, (title, depth), "after_all", &hooks, None);
+                panic::resume_unwind(cause)
+            }
+        };
+        let hooks: Vec<Hook> = close(&teardown);
+        let failure: Option<String> = suite.tear_down("after_all", &hooks, None);
+        suite.notify(|r| r.group_finished(title, depth));
+        suite.before_each.truncate(before_each);
+        suite.after_each.truncate(after_each);
+        suite.depth = depth - 1;
+        suite.group = parent;
+        suite.skipping = skipping;
+        suite.todo = todo;
+        suite.raise(failure)
+    }
+}
+
+///
+/// # Send an event to all the reporters
+///
+/// - `reporters` The reporters
+/// - `event` The event
+///
+fn notify(reporters: &Reporters, event: impl Fn(&mut dyn Reporter)) {
+    if let Ok(mut reporters) = reporters.lock() {
+        for reporter in reporters.iter_mut() {
+            event(reporter.as_mut());
+        }
+    }
+}
+
+///
+/// # Open the after all hooks scope of a group
+///
+/// - `teardown` The scopes of the running groups
+///
+fn open(teardown: &Teardown) {
+    if let Ok(mut teardown) = teardown.lock() {
+        teardown.push(Vec::new());
+    }
+}
+
+///
+/// # Close the after all hooks scope of the innermost group
+///
+/// - `teardown` The scopes of the running groups
+///
+fn close(teardown: &Teardown) -> Vec<Hook> {
+    teardown
+        .lock()
+        .ok()
+        .and_then(|mut teardown| teardown.pop())
+        .unwrap_or_default()
+}
+
+///
+/// # Run teardown hooks, even after a panic, and report their failures
+///
+/// A panicking hook don't stop the next ones, it gives a failed record.
+///
+/// - `reporters` The reporters
+/// - `(group, depth)` The group running the hooks and its depth
+/// - `name` The hook name
+/// - `hooks` The callbacks, run in the reverse order
+/// - `hook_async` The async callback, run last
+///
+fn tear_down(
+    reporters: &Reporters,
+    (group, depth): (&str, usize),
+    name: &str,
+    hooks: &[Hook],
+    hook_async: Option<AsyncHook>,
+) -> Vec<Record> {
+    let mut records: Vec<Record> = hooks
+        .iter()
+        .rev()
+        .filter_map(|hook| guard(reporters, (group, depth), name, || hook()))
+        .collect();
+    if hook_async.is_some() {
+        records.extend(guard(reporters, (group, depth), name, || {
+            executor::hook(hook_async);
+        }));
     }
+    records
+}
 
-    ///
-    /// # Check if the callback exit code match the expected exit code
-    ///
-    /// - `actual` The callback to check
-    /// - `expected` The expected code
-    ///
-    #[must_use]
-    pub fn response<X: PartialEq>(
-        self,
-        title: &str,
-        description: &str,
-        c: &dyn Fn(X) -> X,
-        x: X,
-        expected: &X,
-    ) -> Self {
-        self.title(title, description)
-            .run(c(x).eq(expected), IS_EQUALS, IS_UNEQUALS)
+///
+/// # Run a teardown callback, even after a panic, and report its failure
+///
+/// - `reporters` The reporters
+/// - `(group, depth)` The group running the callback and its depth
+/// - `name` The hook name
+/// - `callback` The callback
+///
+fn guard(
+    reporters: &Reporters,
+    (group, depth): (&str, usize),
+    name: &str,
+    callback: impl FnOnce(),
+) -> Option<Record> {
+    let record: Option<Record> =
+        panic::catch_unwind(AssertUnwindSafe(callback))
+            .err()
+            .map(|cause| {
+                let reason: &str = cause
+                    .downcast_ref::<String>()
+                    .map(String::as_str)
+                    .or_else(|| cause.downcast_ref::<&str>().copied())
+                    .unwrap_or_default();
+                Record::new(
+                    name,
+                    &format!("{IS_TEARDOWN_FAILED}: {reason}"),
+                    Outcome::Failed,
+                    0,
+                    group,
+                )
+            });
+    if let Some(record) = &record {
+        notify(reporters, |r| r.assertion(record, depth));
     }
+    notify(reporters, |r| r.hook_executed(name));
+    record
+}
 
-    ///
-    /// # Check if a theorem is equal to expected value
-    ///
-    /// - `c` The theorem callback
-    /// - `expected` The expected value
-    ///
-    #[must_use]
-    pub fn theorem<X: PartialEq>(
-        self,
-        title: &str,
-        description: &str,
-        c: &dyn Fn() -> X,
-        expected: &X,
-    ) -> Self {
-        self.sub_title(title, description)
-            .run(c().eq(expected), IS_OK, IS_KO)
-    }
+///
+/// # A suite sharing a typed context with its groups
+///
+pub struct Fixture<'a, T> {
+    suite: Suite,
+    context: &'a mut T,
+}
 
+impl<T> Fixture<'_, T> {
     ///
-    /// # Check if a theorem is equal to expected value
+    /// # Run a group of tests with the context
     ///
-    /// - `c` The theorem callback
-    /// - `expected` The expected value
+    /// - `title` The group title
+    /// - `description` The group description
+    /// - `callback` The group tests
     ///
     #[must_use]
-    pub fn theory<X: PartialEq>(
-        self,
+    pub fn group(
+        mut self,
         title: &str,
         description: &str,
-        callback: &X,
-        expected: &X,
+        callback: impl FnOnce(Suite, &mut T) -> Suite,
     ) -> Self {
-        self.sub_title(title, description).run(
-            callback.eq(expected),
-            THEORY_IS_TRUE,
-            THEORY_IS_FALSE,
-        )
+        let group: Pending = Pending {
+            title: title.to_string(),
+            description: description.to_string(),
+            tags: Vec::new(),
+            mode: Mode::Run,
+            callback: Arc::new(|s| s),
+        };
+        let context: &mut T = self.context;
+        self.suite = self.suite.run_group(&group, |s| callback(s, context));
+        self
     }
 
     ///
-    /// # Check if a theorem is different to expected
+    /// # Run tests outside of a group
     ///
-    /// - `c` The theorem callback
-    /// - `expected` The expected value
+    /// - `callback` The tests
     ///
     #[must_use]
-    pub fn chaos<X: PartialEq>(
-        self,
-        title: &str,
-        description: &str,
-        c: &dyn Fn() -> X,
-        expected: &X,
-    ) -> Self {
-        self.title(title, description)
-            .run(c().ne(expected), THEORY_IS_TRUE, THEORY_IS_FALSE)
-    }
-    fn title(self, title: &str, description: &str) -> Self {
-        println!("\n{title}\n\n\t{description}\n");
-        self
-    }
-
-    fn sub_title(self, title: &str, description: &str) -> Self {
-        println!("\t{title}\n\n\t{description}\n");
+    pub fn then(mut self, callback: impl FnOnce(Suite) -> Suite) -> Self {
+        self.suite = callback(self.suite);
         self
     }
 
     ///
-    /// # Check if actual is lower than expected
-    ///
-    /// - `description` The actual value
-    /// - `expected` The expected value
+    /// # Get the context
     ///
-    #[must_use]
-    pub fn group(self, title: &str, description: &str, callback: fn(Self) -> Self) -> Self {
-        callback(self.title(title, description))
+    pub fn context(&mut self) -> &mut T {
+        self.context
     }
 }
 
+///
+/// # A before all or an after all hook, sync or async
+///
+type AllHook = (Option<Hook>, Option<AsyncHook>);
+
+///
+/// # Share a hook
+///
+/// - `hook` The callback
+///
+fn shared(hook: fn()) -> Hook {
+    Arc::new(hook)
+}
+
 ///
 /// # Start a test suite
 ///
@@ -334,23 +1781,235 @@ pub fn describe(
     before_each_hook: Option<fn()>,
     main: fn(Suite) -> Suite,
 ) -> Suite {
-    if let Some(a) = before_all_hook {
-        a();
-    }
-    println!("\n{title}\n\n{description}\n");
-    let data: Suite = main(Suite::new(before_each_hook, after_each_hook));
-    if let Some(b) = after_all_hook {
-        b();
-    }
-    data
+    execute(
+        Suite::new(before_each_hook, after_each_hook),
+        (title, description),
+        (
+            (before_all_hook.map(shared), None),
+            (after_all_hook.map(shared), None),
+        ),
+        main,
+    )
+}
+
+///
+/// # Start a test suite recording all the failures
+///
+/// The failures are returned by `Suite::end` instead of panicking on the first one.
+///
+/// - `description`         The test suite description
+/// - `after_all_hook`      A callback
+/// - `after_each_hook`     A callback
+/// - `before_all_hook`     A callback
+/// - `before_each_hook`    A callback
+/// - `main`                The main callback
+///
+pub fn describe_soft(
+    title: &str,
+    description: &str,
+    after_all_hook: Option<fn()>,
+    after_each_hook: Option<fn()>,
+    before_all_hook: Option<fn()>,
+    before_each_hook: Option<fn()>,
+    main: fn(Suite) -> Suite,
+) -> Suite {
+    execute(
+        Suite::new(before_each_hook, after_each_hook).soft(),
+        (title, description),
+        (
+            (before_all_hook.map(shared), None),
+            (after_all_hook.map(shared), None),
+        ),
+        main,
+    )
+}
+
+///
+/// # Start a test suite with a default timeout
+///
+/// - `description`         The test suite description
+/// - `timeout`             The default timeout of the async tests and the `in_time` callbacks
+/// - `after_all_hook`      A callback
+/// - `after_each_hook`     A callback
+/// - `before_all_hook`     A callback
+/// - `before_each_hook`    A callback
+/// - `main`                The main callback
+///
+#[allow(clippy::too_many_arguments)]
+pub fn describe_within(
+    title: &str,
+    description: &str,
+    timeout: Duration,
+    after_all_hook: Option<fn()>,
+    after_each_hook: Option<fn()>,
+    before_all_hook: Option<fn()>,
+    before_each_hook: Option<fn()>,
+    main: fn(Suite) -> Suite,
+) -> Suite {
+    execute(
+        Suite::new(before_each_hook, after_each_hook).timeout(timeout),
+        (title, description),
+        (
+            (before_all_hook.map(shared), None),
+            (after_all_hook.map(shared), None),
+        ),
+        main,
+    )
+}
+
+///
+/// # Start a test suite with async hooks
+///
+/// - `description`         The test suite description
+/// - `after_all_hook`      An async callback
+/// - `after_each_hook`     An async callback
+/// - `before_all_hook`     An async callback
+/// - `before_each_hook`    An async callback
+/// - `main`                The main callback
+///
+pub fn describe_async(
+    title: &str,
+    description: &str,
+    after_all_hook: Option<AsyncHook>,
+    after_each_hook: Option<AsyncHook>,
+    before_all_hook: Option<AsyncHook>,
+    before_each_hook: Option<AsyncHook>,
+    main: fn(Suite) -> Suite,
+) -> Suite {
+    let mut suite: Suite = Suite::new(None, None);
+    suite.before_each_async = before_each_hook;
+    suite.after_each_async = after_each_hook;
+    execute(
+        suite,
+        (title, description),
+        ((None, before_all_hook), (None, after_all_hook)),
+        main,
+    )
+}
+
+///
+/// # Start a test suite sharing a typed context
+///
+/// The context is built once before the tests, lent to each group and consumed after the tests,
+/// even when a test panics.
+///
+/// - `title`               The test suite title
+/// - `description`         The test suite description
+/// - `before_all`          The callback building the context
+/// - `after_all`           The callback consuming the context
+/// - `main`                The main callback
+///
+pub fn describe_with<T>(
+    title: &str,
+    description: &str,
+    before_all: impl FnOnce() -> T,
+    after_all: impl FnOnce(T),
+    main: impl FnOnce(Fixture<'_, T>) -> Fixture<'_, T>,
+) -> Suite {
+    execute(
+        Suite::new(None, None),
+        (title, description),
+        ((None, None), (None, None)),
+        |suite| {
+            let mut context: T = before_all();
+            suite.notify(|r| r.hook_executed("before_all"));
+            let reporters: Reporters = Arc::clone(&suite.reporters);
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                main(Fixture {
+                    suite,
+                    context: &mut context,
+                })
+                .suite
+            }));
+            let mut suite: Suite = match result {
+                Ok(suite) => suite,
+                Err(cause) => {
+                    guard(&reporters, (title, 0), "after_all", || after_all(context));
+                    panic::resume_unwind(cause)
+                }
+            };
+            let record: Option<Record> =
+                guard(&reporters, (&suite.group, suite.depth), "after_all", || {
+                    after_all(context)
+                });
+            let failure: Option<String> = record.as_ref().map(|r| r.message.clone());
+            suite.report.records.extend(record);
+            suite.raise(failure)
+        },
+    )
+}
+
+///
+/// # Run the main callback of a test suite between its header and its footer
+///
+/// - `suite`           The suite to run
+/// - `header`          The test suite title and description
+/// - `all`             The before all and the after all hooks
+/// - `main`            The main callback
+///
+fn execute(
+    mut suite: Suite,
+    (title, description): (&str, &str),
+    (before_all, after_all): (AllHook, AllHook),
+    main: impl FnOnce(Suite) -> Suite,
+) -> Suite {
+    suite.notify(|r| r.suite_started(title, description));
+    suite.hook("before_all", before_all.0.as_ref(), before_all.1);
+    suite.report = Report::new(title, description);
+    suite.group = title.to_string();
+    suite.skipping = suite.filter.is_restrictive() && !suite.filter.includes(title);
+    let (reporters, teardown): (Reporters, Teardown) =
+        (Arc::clone(&suite.reporters), Arc::clone(&suite.teardown));
+    let hooks = || -> Vec<Hook> {
+        after_all
+            .0
+            .iter()
+            .cloned()
+            .chain(close(&teardown))
+            .collect()
+    };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        if suite.filter.excludes(title) {
+            suite.unchecked("describe", title, Outcome::Skipped)
+        } else {
+            main(suite)
+        }
+    }));
+    let mut data: Suite = match result {
+        Ok(data) => data,
+        Err(cause) => {
+            tear_down(&reporters, (title, 0), "after_all", &hooks(), after_all.1);
+            panic::resume_unwind(cause)
+        }
+    };
+    let failure: Option<String> = data.tear_down("after_all", &hooks(), after_all.1);
+    data.finish();
+    data.raise(failure)
 }
 
 #[cfg(test)]
 mod test {
-    use crate::suite::Suite;
+    use crate::approx::Tolerance;
+    use crate::command::cmd;
+    use crate::filter::Filter;
+    use crate::matcher;
+    use crate::output::{IS_NOT_APPROX_EQUALS, IS_SPAWN_FAILED, IS_TEARDOWN_FAILED, IS_TIMEOUT};
+    use crate::property::{self, between};
+    use crate::report::{Outcome, Record};
+    use crate::reporter::Recorder;
+    use crate::snapshot;
+    use crate::suite::{
+        describe_async, describe_soft, describe_with, describe_within, Suite, SuiteError,
+    };
     use crate::{always_panic, it};
     use std::fs;
+    use std::future::Future;
+    use std::io;
     use std::ops::Mul;
+    use std::panic::RefUnwindSafe;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
 
     fn main(s: Suite) -> Suite {
         s.group(
@@ -498,4 +2157,541 @@ mod test {
             main
         );
     }
+
+    fn hook() -> Pin<Box<dyn Future<Output = ()>>> {
+        Box::pin(async {})
+    }
+
+    #[test]
+    fn hooks() {
+        let report = describe_async(
+            "Check the async hooks",
+            "Async hooks run around each test",
+            Some(hook),
+            Some(hook),
+            Some(hook),
+            Some(hook),
+            |s| s.eq_async(async { 2 }, &2),
+        )
+        .end_with_report();
+        assert!(report.is_success());
+    }
+
+    #[test]
+    fn filter() {
+        let report = Suite::new(None, None)
+            .filter(Filter::parse("equal, !slow"))
+            .group("Should be equals", "Selected by substring", |s| {
+                s.eq(&1, &1)
+                    .group("Should be slow", "Excluded", |s| s.eq(&1, &2))
+            })
+            .group("Should be contains", "Not selected", |s| {
+                s.str_contains("linux", "windows").group(
+                    "Should be equal",
+                    "Selected inside a skipped group",
+                    |s| s.eq(&2, &2),
+                )
+            })
+            .end_with_report();
+        let outcomes: Vec<(&str, Outcome)> = report
+            .records
+            .iter()
+            .map(|r| (r.kind.as_str(), r.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("eq", Outcome::Passed),
+                ("group", Outcome::Skipped),
+                ("str_contains", Outcome::Skipped),
+                ("eq", Outcome::Passed),
+            ]
+        );
+    }
+
+    #[test]
+    fn markers() {
+        let report = Suite::new(None, None)
+            .filter(Filter::parse("!@slow"))
+            .group_tagged("Should be slow", "Excluded by tag", &["slow"], |s| {
+                s.eq(&1, &2)
+            })
+            .group_todo("Should be done", "Unfinished", |s| s.eq(&1, &1).eq(&1, &2))
+            .group_skip("Should be skipped", "Skipped", |s| s.eq(&1, &2))
+            .eq(&3, &3)
+            .batch(|s| {
+                s.group("Should be equals", "Sibling of only", |s| s.eq(&1, &2))
+                    .group_only("Should be only", "Focused", |s| s.eq(&2, &2))
+            })
+            .group("Should be run", "Declared after the batch", |s| {
+                s.eq(&4, &4)
+            })
+            .end_with_report();
+        let outcomes: Vec<(&str, Outcome)> = report
+            .records
+            .iter()
+            .map(|r| (r.kind.as_str(), r.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("group", Outcome::Skipped),
+                ("eq", Outcome::Passed),
+                ("eq", Outcome::Todo),
+                ("group", Outcome::Skipped),
+                ("eq", Outcome::Passed),
+                ("group", Outcome::Skipped),
+                ("eq", Outcome::Passed),
+                ("eq", Outcome::Passed),
+            ]
+        );
+        assert_eq!(
+            (report.skipped(), report.todo(), report.failures()),
+            (3, 1, 0)
+        );
+        let eager = Suite::new(None, None).group("Should be run", "Last", |s| s.eq(&1, &1));
+        assert_eq!(eager.report.records.len(), 1);
+    }
+
+    #[test]
+    fn reporter() {
+        let recorder = Recorder::default().failing("unwritable");
+        let mut suite = Suite::new(None, None)
+            .reporter(recorder.clone())
+            .eq(&1, &1)
+            .group("Should be equals", "First level", |s| {
+                s.group("Should be unequals", "Second level", |s| s.ne(&1, &2))
+            });
+        assert!(matches!(suite.end(), Err(SuiteError::Io(_))));
+        assert_eq!(
+            recorder.events(),
+            vec!["eq 0", "Should be equals 1", "Should be unequals 2", "ne 2"]
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        fn finish(mut suite: Suite) -> io::Result<()> {
+            suite.end()?;
+            Ok(())
+        }
+        assert!(finish(Suite::new(None, None).eq(&1, &1)).is_ok());
+        let error = finish(Suite::new(None, None).soft().eq(&1, &2)).expect_err("a failure");
+        assert!(error.to_string().starts_with("1 failure(s)"));
+        let unwritable =
+            finish(Suite::new(None, None).reporter(Recorder::default().failing("unwritable")))
+                .expect_err("an unwritable report");
+        assert_eq!(unwritable.to_string(), "unwritable");
+        assert!(matches!(
+            SuiteError::from(io::Error::other("disk full")),
+            SuiteError::Io(_)
+        ));
+    }
+
+    #[test]
+    fn parallel() {
+        let report = Suite::new(None, None)
+            .soft()
+            .batch(|s| {
+                s.group_parallel("Should be slow", "Runs on a worker", |s| {
+                    std::thread::sleep(std::time::Duration::from_millis(50));
+                    s.eq(&1, &1).eq(&1, &2)
+                })
+                .group_parallel("Should be fast", "Runs on a worker", |s| {
+                    s.str_contains("linux", "nux")
+                })
+                .group_parallel("Should panic", "Runs on a worker", |_| panic!("worker"))
+            })
+            .eq(&3, &3)
+            .end_with_report();
+        let outcomes: Vec<(&str, &str, Outcome)> = report
+            .records
+            .iter()
+            .map(|r| (r.group.as_str(), r.kind.as_str(), r.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("Should be slow", "eq", Outcome::Passed),
+                ("Should be slow", "eq", Outcome::Failed),
+                ("Should be fast", "str_contains", Outcome::Passed),
+                ("Should panic", "group", Outcome::Failed),
+                ("", "eq", Outcome::Passed),
+            ]
+        );
+        let nested = |s: Suite| {
+            s.eq(&1, &1)
+                .group("Should be nested", "Second level", |s| s.ne(&1, &2))
+        };
+        let sequential = Recorder::default();
+        let _ = Suite::new(None, None)
+            .reporter(sequential.clone())
+            .group("Should be equals", "First level", nested)
+            .end_with_report();
+        let workers = Recorder::default();
+        let _ = Suite::new(None, None)
+            .reporter(workers.clone())
+            .batch(|s| s.group_parallel("Should be equals", "First level", nested))
+            .end_with_report();
+        assert_eq!(
+            workers.events(),
+            vec!["Should be equals 1", "eq 1", "Should be nested 2", "ne 2"]
+        );
+        assert_eq!(workers.events(), sequential.events());
+        let strict = std::panic::catch_unwind(|| {
+            let _ = Suite::new(None, None).batch(|s| {
+                s.group_parallel("Should fail", "Outside of the soft mode", |s| s.eq(&1, &2))
+            });
+        })
+        .expect_err("a failed parallel group must panic outside of the soft mode");
+        assert_eq!(
+            strict.downcast_ref::<String>().map(String::as_str),
+            Some("equal to 2, was 1")
+        );
+    }
+
+    #[test]
+    fn soft() {
+        let result = describe_soft(
+            "Check the soft mode",
+            "All the failures must be returned at the end",
+            None,
+            None,
+            None,
+            None,
+            |s| {
+                s.eq(&1, &2)
+                    .group("Should continue", "The chain must be executed", |s| {
+                        s.ok(&data(2)).ok(&data(3))
+                    })
+                    .eq(&3, &3)
+                    .expect("uuid", matcher::str_contains("-"))
+                    .approx_eq(&1.0, &1.5, Tolerance::Absolute(0.1))
+                    .command(
+                        &cmd(env!("CARGO"))
+                            .arg("--version")
+                            .code(0)
+                            .stdout_matches("^cargo "),
+                    )
+                    .command(&cmd("./does-not-exist").code(0))
+            },
+        )
+        .end();
+        match result {
+            Err(SuiteError::Failures(failures)) => {
+                assert_eq!(failures.len(), 5);
+                assert_eq!(failures[0].message, "equal to 2, was 1");
+                assert_eq!(failures[1].group, "Should continue");
+                assert_eq!(failures[2].message, "containing \"-\", was \"uuid\"");
+                assert_eq!(
+                    failures[3].message,
+                    format!(
+                        "{IS_NOT_APPROX_EQUALS}: 1 and 1.5 differ by 5e-1, the tolerance is absolute 1e-1"
+                    )
+                );
+                assert!(failures[4].message.starts_with(IS_SPAWN_FAILED));
+            }
+            _ => panic!("a soft suite must return its failures"),
+        }
+    }
+
+    #[test]
+    fn futures() {
+        let report = Suite::new(None, None)
+            .soft()
+            .timeout(Duration::from_millis(50))
+            .eq_async(async { c(3) }, &0)
+            .theory_async(
+                "ab = 3; bc = 4",
+                "ac == 5",
+                async { is_rect(3.0, 4.0) },
+                &5.0,
+            )
+            .panic_async(async { panic() })
+            .not_panic_async(async {})
+            .eq_async(async { 3 }, &4)
+            .eq_async(std::future::pending::<i32>(), &4)
+            .end_with_report();
+        let passed: Vec<bool> = report.records.iter().map(Record::passed).collect();
+        assert_eq!(passed, vec![true, true, true, true, false, false]);
+        assert!(report.records[5].message.contains("50"));
+    }
+
+    #[test]
+    fn properties() {
+        let report = Suite::new(None, None)
+            .soft()
+            .for_all(
+                "x² % 3",
+                "x² % 3 is never 2",
+                between(-1000, 1000),
+                &|x| c(x).ne(&2),
+            )
+            .for_all("x < 100", "x is small", between(0_u32, 1000), &|x| x < 100)
+            .end_with_report();
+        let passed: Vec<bool> = report.records.iter().map(Record::passed).collect();
+        assert_eq!(passed, vec![true, false]);
+        assert!(report.records[1].message.contains(property::SEED));
+    }
+
+    #[test]
+    fn snapshots() {
+        let report = Suite::new(None, None)
+            .soft()
+            .snapshot("equals", &format!("{:?}", vec![1, 2]))
+            .snapshot("equals", &format!("{:?}", vec![2, 1]))
+            .end_with_report();
+        let _ = fs::remove_file(snapshot::path(file!(), "equals").with_extension("snap.new"));
+        let passed: Vec<bool> = report.records.iter().map(Record::passed).collect();
+        assert_eq!(passed, vec![true, false]);
+        assert_eq!(report.records[1].expected.as_deref(), Some("[1, 2]"));
+    }
+
+    #[test]
+    fn values() {
+        let report = Suite::new(None, None)
+            .soft()
+            .eq_debug(&vec![1, 2], &vec![1, 2])
+            .ne_debug(&Some(1), &None)
+            .str_eq("linux", "linux")
+            .str_eq("linux", "linus")
+            .end_with_report();
+        let passed: Vec<bool> = report.records.iter().map(Record::passed).collect();
+        assert_eq!(passed, vec![true, true, true, false]);
+        assert_eq!(report.records[3].expected.as_deref(), Some("linus"));
+        assert_eq!(report.records[3].actual.as_deref(), Some("linux"));
+    }
+
+    #[test]
+    fn take() {
+        let report = Suite::new(None, None)
+            .not_panic(|| std::thread::sleep(Duration::from_millis(20)))
+            .eq(&1, &1)
+            .before_each(|| std::thread::sleep(Duration::from_millis(20)))
+            .eq(&1, &1)
+            .end_with_report();
+        assert!(report.records[0].take >= 20_000_000);
+        assert!(report.records[1].take < 20_000_000);
+        assert!(report.records[2].take < 20_000_000);
+    }
+
+    #[test]
+    fn fixture() {
+        let calls = Arc::new(Mutex::new(0));
+        let counter = Arc::clone(&calls);
+        let report = describe_with(
+            "Check the fixtures",
+            "Groups share the context built before all",
+            || vec![1],
+            |context: Vec<u8>| assert_eq!(context, vec![1, 2, 3]),
+            |fixture| {
+                fixture
+                    .then(move |s| {
+                        s.before_each(move || {
+                            if let Ok(mut calls) = counter.lock() {
+                                *calls += 1;
+                            }
+                        })
+                    })
+                    .group("Should push", "The context is mutable", |s, v| {
+                        v.push(2);
+                        s.eq(&v.len(), &2)
+                    })
+                    .group("Should push again", "The context is shared", |s, v| {
+                        v.push(3);
+                        s.eq(&v.len(), &3)
+                    })
+            },
+        )
+        .end_with_report();
+        assert_eq!(report.failures(), 0);
+        assert_eq!(calls.lock().map(|c| *c).unwrap_or_default(), 2);
+    }
+
+    #[test]
+    fn released() {
+        let released = Arc::new(Mutex::new(Vec::new()));
+        let context = Arc::clone(&released);
+        let failed = std::panic::catch_unwind(|| {
+            describe_with(
+                "Check the fixture teardown",
+                "The context is consumed when a group panics",
+                || vec![1],
+                move |v: Vec<u8>| {
+                    if let Ok(mut released) = context.lock() {
+                        released.extend(v);
+                    }
+                },
+                |fixture| {
+                    fixture.group("Should fail", "The group panics", |s, v| {
+                        v.push(2);
+                        s.eq(&1, &2)
+                    })
+                },
+            )
+        });
+        assert!(failed.is_err());
+        assert_eq!(
+            released.lock().map(|r| r.clone()).unwrap_or_default(),
+            vec![1, 2]
+        );
+    }
+
+    fn log(
+        calls: &Arc<Mutex<Vec<&'static str>>>,
+        call: &'static str,
+    ) -> impl Fn() + Clone + Send + Sync + RefUnwindSafe + 'static {
+        let calls = Arc::clone(calls);
+        move || {
+            if let Ok(mut calls) = calls.lock() {
+                calls.push(call);
+            }
+        }
+    }
+
+    #[test]
+    fn scoped() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let (outer, inner) = (log(&calls, "outer"), log(&calls, "inner"));
+        let (teardown, closed) = (log(&calls, "teardown"), log(&calls, "closed"));
+        let _ = Suite::new(None, None)
+            .before_each(outer)
+            .group("Should nest", "The parent hooks are inherited", move |s| {
+                s.after_each(teardown.clone())
+                    .group("Should be inner", "The hooks run in order", {
+                        let (inner, closed) = (inner.clone(), closed.clone());
+                        move |s| {
+                            s.before_each(inner.clone())
+                                .after_all(closed.clone())
+                                .eq(&1, &1)
+                        }
+                    })
+                    .eq(&2, &2)
+            })
+            .eq(&3, &3)
+            .end_with_report();
+        assert_eq!(
+            calls.lock().map(|c| c.clone()).unwrap_or_default(),
+            vec!["outer", "inner", "teardown", "closed", "outer", "teardown", "outer"]
+        );
+    }
+
+    #[test]
+    fn teardown() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let (each, all) = (log(&calls, "each"), log(&calls, "all"));
+        let failed = std::panic::catch_unwind(|| {
+            Suite::new(None, None)
+                .after_each(each)
+                .group("Should clean", "The teardown runs on failure", move |s| {
+                    s.after_all(all.clone()).eq(&1, &2)
+                })
+                .end_with_report()
+        });
+        assert!(failed.is_err());
+        assert_eq!(
+            calls.lock().map(|c| c.clone()).unwrap_or_default(),
+            vec!["each", "all"]
+        );
+        let report = Suite::new(None, None)
+            .soft()
+            .after_each(|| panic!("broken"))
+            .eq(&1, &1)
+            .end_with_report();
+        let outcomes: Vec<(&str, Outcome)> = report
+            .records
+            .iter()
+            .map(|r| (r.kind.as_str(), r.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![("eq", Outcome::Passed), ("after_each", Outcome::Failed)]
+        );
+        assert_eq!(
+            report.records[1].message,
+            format!("{IS_TEARDOWN_FAILED}: broken")
+        );
+        let (scoped, released) = (log(&calls, "scoped"), log(&calls, "released"));
+        let failed = std::panic::catch_unwind(move || {
+            describe_with(
+                "Check the fixture teardown",
+                "The fixture and the scoped hooks run on failure",
+                || (),
+                move |()| {
+                    released();
+                    panic!("leaked");
+                },
+                |fixture| {
+                    fixture.then(|s| s.after_all(scoped)).group(
+                        "Should fail",
+                        "The group panics",
+                        |s, ()| s.eq(&1, &2),
+                    )
+                },
+            )
+        });
+        let cause = failed.err().and_then(|c| c.downcast::<String>().ok());
+        assert!(cause.is_some_and(|c| !c.contains("leaked")));
+        assert_eq!(
+            calls.lock().map(|c| c[2..].to_vec()).unwrap_or_default(),
+            vec!["released", "scoped"]
+        );
+        let report = describe_with(
+            "Check the fixture teardown",
+            "A panicking fixture teardown is a failure",
+            || (),
+            |()| panic!("leaked"),
+            |fixture| fixture.then(|s| s.soft().eq(&1, &1)),
+        )
+        .end_with_report();
+        assert_eq!(report.failures(), 1);
+        assert_eq!(
+            report.records[1].message,
+            format!("{IS_TEARDOWN_FAILED}: leaked")
+        );
+    }
+
+    #[test]
+    fn timeout() {
+        let report = describe_within(
+            "Check the timeouts",
+            "A callback completed in time must pass",
+            Duration::from_secs(1),
+            None,
+            None,
+            None,
+            None,
+            |s| {
+                s.in_time(ok)
+                    .within(Duration::from_secs(1), ok)
+                    .in_time(|| c(3).eq(&0))
+            },
+        )
+        .end_with_report();
+        assert!(report.is_success());
+        assert_eq!(report.records.len(), 3);
+        let late = Suite::new(None, None)
+            .soft()
+            .within(Duration::from_millis(20), || {
+                std::thread::sleep(Duration::from_secs(1));
+                true
+            })
+            .within(Duration::from_secs(1), || false)
+            .end_with_report();
+        let passed: Vec<bool> = late.records.iter().map(Record::passed).collect();
+        assert_eq!(passed, vec![false, false]);
+        let hung = Suite::new(None, None).timeout(Duration::from_millis(20));
+        let error = std::panic::catch_unwind(|| {
+            hung.in_time(|| {
+                std::thread::sleep(Duration::from_secs(1));
+                true
+            })
+        })
+        .err()
+        .expect("a hung callback must fail");
+        assert_eq!(
+            error.downcast_ref::<String>().map(String::as_str),
+            Some(format!("{IS_TIMEOUT} 20 ms").as_str())
+        );
+    }
 }
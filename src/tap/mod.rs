@@ -0,0 +1,299 @@
+use std::fmt::Write as _;
+
+use crate::report::{Outcome, Record, Report};
+use crate::reporter::Reporter;
+
+///
+/// # The indentation of a subtest level
+///
+const INDENT: &str = "    ";
+
+///
+/// # Build the indentation of a depth
+///
+/// - `depth` The subtest depth
+///
+#[must_use]
+pub fn indent(depth: usize) -> String {
+    INDENT.repeat(depth)
+}
+
+///
+/// # Build the version line
+///
+/// - `version` The protocol version
+///
+#[must_use]
+pub fn version(version: u8) -> String {
+    format!("TAP version {version}")
+}
+
+///
+/// # Build the plan line
+///
+/// - `total` The number of tests
+/// - `depth` The subtest depth
+///
+#[must_use]
+pub fn plan(total: usize, depth: usize) -> String {
+    format!("{}1..{total}", indent(depth))
+}
+
+///
+/// # Build the subtest header
+///
+/// - `title` The subtest title
+/// - `depth` The subtest depth
+///
+#[must_use]
+pub fn subtest(title: &str, depth: usize) -> String {
+    format!("{}# Subtest: {title}", indent(depth))
+}
+
+///
+/// # Build a test point line
+///
+/// - `ok` The test result
+/// - `n` The test number
+/// - `description` The test description
+/// - `depth` The subtest depth
+///
+#[must_use]
+pub fn point(ok: bool, n: usize, description: &str, depth: usize) -> String {
+    let status = if ok { "ok" } else { "not ok" };
+    format!(
+        "{}{status} {n} - {}",
+        indent(depth),
+        description.replace('#', "\\#")
+    )
+}
+
+///
+/// # Quote a value for a yaml diagnostic
+///
+/// - `value` The value to quote
+///
+fn quote(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    )
+}
+
+///
+/// # Build the yaml diagnostic block of a failed record
+///
+/// - `record` The failed record
+/// - `depth` The subtest depth
+///
+#[must_use]
+pub fn diagnostic(record: &Record, depth: usize) -> String {
+    let i = format!("{}  ", indent(depth));
+    let mut yaml = format!("{i}---\n");
+    let _ = writeln!(yaml, "{i}message: {}", quote(&record.message));
+    let _ = writeln!(yaml, "{i}severity: fail");
+    let _ = writeln!(yaml, "{i}operator: {}", quote(&record.kind));
+    if let Some(expected) = &record.expected {
+        let _ = writeln!(yaml, "{i}expected: {}", quote(expected));
+    }
+    if let Some(actual) = &record.actual {
+        let _ = writeln!(yaml, "{i}actual: {}", quote(actual));
+    }
+    if let Some(at) = record.at() {
+        let _ = writeln!(yaml, "{i}at: {}", quote(&at));
+    }
+    let _ = writeln!(yaml, "{i}duration_ns: {}", record.take);
+    let _ = write!(yaml, "{i}...");
+    yaml
+}
+
+///
+/// # Build the test point of a record with its diagnostic on failure
+///
+/// - `record` The record
+/// - `n` The test number
+/// - `depth` The subtest depth
+///
+#[must_use]
+pub fn record(record: &Record, n: usize, depth: usize) -> String {
+    let line = point(
+        record.passed() || record.skipped(),
+        n,
+        &record.message,
+        depth,
+    );
+    match record.outcome {
+        Outcome::Passed => line,
+        Outcome::Failed => format!("{line}\n{}", diagnostic(record, depth)),
+        Outcome::Skipped => format!("{line} # SKIP"),
+        Outcome::Todo => format!("{line} # TODO"),
+    }
+}
+
+///
+/// # Render a report as a tap document
+///
+/// - `report` The report to render
+/// - `v` The protocol version
+///
+#[must_use]
+pub fn render(report: &Report, v: u8) -> String {
+    let mut tap = format!("{}\n", version(v));
+    if !report.title.is_empty() {
+        let _ = writeln!(tap, "# {}", report.title);
+    }
+    for (n, r) in report.records.iter().enumerate() {
+        let _ = writeln!(tap, "{}", record(r, n + 1, 0));
+    }
+    let _ = writeln!(tap, "{}", plan(report.total(), 0));
+    tap
+}
+
+///
+/// # The reporter writing a tap stream with a subtest by group
+///
+#[derive(Clone, Debug)]
+pub struct TapReporter {
+    version: u8,
+    levels: Vec<Level>,
+}
+
+///
+/// # The test count and the failure state of a subtest
+///
+#[derive(Clone, Copy, Debug, Default)]
+struct Level {
+    points: usize,
+    failed: bool,
+}
+
+impl TapReporter {
+    ///
+    /// # Initialize the reporter
+    ///
+    /// - `version` The protocol version
+    ///
+    #[must_use]
+    pub fn new(version: u8) -> Self {
+        Self {
+            version,
+            levels: vec![Level::default()],
+        }
+    }
+
+    ///
+    /// # Increment and return the test number of the current subtest
+    ///
+    /// - `failed` The test failed
+    ///
+    fn point(&mut self, failed: bool) -> usize {
+        if self.levels.is_empty() {
+            self.levels.push(Level::default());
+        }
+        let level: &mut Level = self.levels.last_mut().expect("no tap level");
+        level.points += 1;
+        level.failed |= failed;
+        level.points
+    }
+
+    ///
+    /// # Build the test point of an assertion
+    ///
+    /// - `record` The record
+    /// - `depth` The subtest depth
+    ///
+    fn test(&mut self, record: &Record, depth: usize) -> String {
+        let n: usize = self.point(record.failed());
+        self::record(record, n, depth)
+    }
+
+    ///
+    /// # Build the plan of a subtest and its test point, failed when one of its tests failed
+    ///
+    /// - `title` The subtest title
+    /// - `depth` The subtest depth
+    ///
+    fn close(&mut self, title: &str, depth: usize) -> String {
+        let level: Level = self.levels.pop().unwrap_or_default();
+        let n: usize = self.point(level.failed);
+        format!(
+            "{}\n{}",
+            plan(level.points, depth),
+            point(!level.failed, n, title, depth.saturating_sub(1))
+        )
+    }
+}
+
+impl Reporter for TapReporter {
+    fn suite_started(&mut self, title: &str, description: &str) {
+        println!("{}\n# {title}: {description}", version(self.version));
+    }
+
+    fn group_started(&mut self, title: &str, _description: &str, depth: usize) {
+        println!("{}", subtest(title, depth.saturating_sub(1)));
+        self.levels.push(Level::default());
+    }
+
+    fn theory_started(&mut self, title: &str, description: &str, depth: usize) {
+        println!("{}# {title}: {description}", indent(depth));
+    }
+
+    fn assertion(&mut self, record: &Record, depth: usize) {
+        println!("{}", self.test(record, depth));
+    }
+
+    fn group_finished(&mut self, title: &str, depth: usize) {
+        println!("{}", self.close(title, depth));
+    }
+
+    fn suite_finished(&mut self, _report: &Report) -> std::io::Result<()> {
+        println!(
+            "{}",
+            plan(self.levels.first().map(|l| l.points).unwrap_or_default(), 0)
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::output::{IS_EQUALS, IS_NOT_BEGIN};
+    use crate::report::{Outcome, Record, Report};
+    use crate::reporter::Reporter;
+    use crate::tap::{render, TapReporter};
+
+    #[test]
+    fn document() {
+        let mut report = Report::new("tap", "Check the tap output");
+        report
+            .records
+            .push(Record::new("eq", IS_EQUALS, Outcome::Passed, 10, "tap"));
+        report.records.push(
+            Record::new("start_with", IS_NOT_BEGIN, Outcome::Failed, 20, "tap")
+                .with_values("abc", "xyz"),
+        );
+        assert_eq!(
+            render(&report, 14),
+            format!(
+                "TAP version 14\n# tap\nok 1 - {IS_EQUALS}\nnot ok 2 - {IS_NOT_BEGIN}\n  ---\n  message: \"{IS_NOT_BEGIN}\"\n  severity: fail\n  operator: \"start_with\"\n  expected: \"abc\"\n  actual: \"xyz\"\n  duration_ns: 20\n  ...\n1..2\n"
+            )
+        );
+    }
+
+    #[test]
+    fn subtests() {
+        let mut tap = TapReporter::new(14);
+        tap.group_started("todo", "A todo failure", 1);
+        let _ = tap.test(&Record::new("eq", "ko", Outcome::Todo, 0, "todo"), 1);
+        assert_eq!(tap.close("todo", 1), "    1..1\nok 1 - todo");
+        tap.group_started("outer", "A failing subtest", 1);
+        let _ = tap.test(&Record::new("eq", "ok", Outcome::Passed, 0, "outer"), 1);
+        tap.group_started("inner", "A failure", 2);
+        let _ = tap.test(&Record::new("eq", "ko", Outcome::Failed, 0, "inner"), 2);
+        assert_eq!(tap.close("inner", 2), "        1..1\n    not ok 2 - inner");
+        assert_eq!(tap.close("outer", 1), "    1..2\nnot ok 2 - outer");
+    }
+}
@@ -1,65 +1,600 @@
+use crate::approx::{Approx, Tolerance};
+use crate::command::Command;
+use crate::executor;
+use crate::filter::Filter;
+use crate::junit::JunitReporter;
+use crate::matcher::{self, Matcher};
 use crate::objects::{Failure, Success, Take, Testable, Theory};
 use crate::output::{
-    IS_BEGIN, IS_BETWEEN, IS_CONTAINS, IS_EQUALS, IS_EXECUTABLE, IS_EXISTS, IS_FAIL, IS_FINNISH,
-    IS_INFERIOR, IS_KO, IS_MATCH, IS_NOT_BEGIN, IS_NOT_BETWEEN, IS_NOT_CONTAINS, IS_NOT_EXECUTABLE,
-    IS_NOT_EXISTS, IS_NOT_FAIL, IS_NOT_FINNISH, IS_NOT_MATCH, IS_NOT_SUCCESS, IS_OK, IS_SUCCESS,
-    IS_SUPERIOR, IS_UNEQUALS, THEORY_IS_FALSE, THEORY_IS_TRUE,
+    ASSERT_PANIC, DISABLE_PROGRESS_TIME, IS_APPROX_EQUALS, IS_BEGIN, IS_CONTAINS, IS_EQUALS,
+    IS_EXECUTABLE, IS_EXISTS, IS_FAIL, IS_FINNISH, IS_INFERIOR, IS_KO, IS_MATCH,
+    IS_NOT_APPROX_EQUALS, IS_NOT_BEGIN, IS_NOT_CONTAINS, IS_NOT_EXECUTABLE, IS_NOT_EXISTS,
+    IS_NOT_FAIL, IS_NOT_FINNISH, IS_NOT_MATCH, IS_NOT_SUCCESS, IS_OK, IS_REPORT_NOT_WRITTEN,
+    IS_REPORT_WRITTEN, IS_SNAPSHOT_CHANGED, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_NEW, IS_SPAWN_FAILED,
+    IS_SUCCESS, IS_SUPERIOR, IS_UNEQUALS, THEORY_IS_FALSE, THEORY_IS_TRUE,
 };
-use colored_truecolor::Colorize;
+use crate::parallel;
+use crate::property::{self, Config, Generator};
+use crate::report::{Outcome, Record, Report};
+use crate::reporter::{self, Reporter};
+use crate::snapshot::{self, Snapshot};
 use is_executable::IsExecutable;
-use progress_bar::{
-    finalize_progress_bar, inc_progress_bar, init_progress_bar_with_eta,
-    print_progress_bar_final_info, print_progress_bar_info, set_progress_bar_action, Color, Style,
-};
 use regex::Regex;
 use std::cell::Cell;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::future::Future;
+use std::panic::Location;
 use std::path::Path;
 use std::process::ExitStatus;
-use std::thread::sleep;
 use std::time::{Duration, Instant};
 use std::{fs, io};
 
+///
+/// # When a unit run fails
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Policy {
+    /// Fail on any failure
+    Any,
+    /// Fail when the failures exceed the threshold
+    Threshold(usize),
+    /// Never fail
+    #[default]
+    Never,
+}
+
+impl Policy {
+    ///
+    /// # Check if a number of failures is accepted
+    ///
+    /// - `failures` The number of failures
+    ///
+    #[must_use]
+    pub fn accept(&self, failures: usize) -> bool {
+        match self {
+            Self::Any => failures.eq(&0),
+            Self::Threshold(threshold) => failures.le(threshold),
+            Self::Never => true,
+        }
+    }
+}
+
 ///
 /// # To run units tests
 ///
 pub struct Unit {
     s: Cell<usize>,
     f: Cell<usize>,
-    sleep: u64,
-    success_take: HashMap<usize, u128>,
-    failure_take: HashMap<usize, u128>,
-    success: HashMap<usize, String>,
-    failure: HashMap<usize, String>,
+    report: Report,
+    reporters: Vec<Box<dyn Reporter>>,
+    values: Option<(String, String)>,
+    clock: Option<Instant>,
+    timeout: Option<Duration>,
+    policy: Policy,
+    todo: bool,
+}
+
+impl Unit {
+    ///
+    /// # Write the junit report in a directory at the end
+    ///
+    /// - `directory` The junit output directory
+    ///
+    pub fn junit(&mut self, directory: &str) -> &mut Self {
+        self.reporter(JunitReporter::new(Some(directory)))
+    }
+
+    ///
+    /// # Add a reporter
+    ///
+    /// A reporter added in a running test is started with it.
+    ///
+    /// - `reporter` The reporter receiving the events of the run
+    ///
+    pub fn reporter(&mut self, mut reporter: impl Reporter + 'static) -> &mut Self {
+        if !self.report.title.is_empty() {
+            reporter.suite_started(&self.report.title, &self.report.description);
+        }
+        self.reporters.push(Box::new(reporter));
+        self
+    }
+
+    ///
+    /// # Add a reporter before the run
+    ///
+    /// - `reporter` The reporter receiving the events of the run
+    ///
+    #[must_use]
+    pub fn with_reporter(mut self, reporter: Box<dyn Reporter>) -> Self {
+        self.reporters.push(reporter);
+        self
+    }
+
+    ///
+    /// # Run the callbacks with the reporters and the policy of the unit
+    ///
+    /// - `title` The title
+    /// - `description` The description
+    /// - `callbacks` The callbacks to execute
+    ///
+    /// # Errors
+    ///
+    /// With the report when the policy rejects the failures
+    ///
+    pub fn run_it(
+        mut self,
+        title: &str,
+        description: &str,
+        callbacks: Vec<&dyn Fn(&mut Self) -> &mut Self>,
+    ) -> Result<Report, Report> {
+        self.execute(title, description, &[], callbacks)
+    }
+
+    ///
+    /// # Run independent callbacks on a pool of workers with the reporters and the policy of the unit
+    ///
+    /// - `title` The title
+    /// - `description` The description
+    /// - `callbacks` The callbacks to execute
+    ///
+    /// # Errors
+    ///
+    /// With the report when the policy rejects the failures
+    ///
+    pub fn run_parallel(
+        mut self,
+        title: &str,
+        description: &str,
+        callbacks: Vec<&(dyn Fn(&mut Self) -> &mut Self + Sync)>,
+    ) -> Result<Report, Report> {
+        let run: &dyn Fn(&mut Self) -> &mut Self = &|u: &mut Self| u.parallel(&callbacks);
+        self.execute(title, description, &[], vec![run])
+    }
+
+    ///
+    /// # Run tagged callbacks with the reporters and the policy of the unit
+    ///
+    /// - `title` The title
+    /// - `description` The description
+    /// - `tags` The tags
+    /// - `callbacks` The callbacks to execute
+    ///
+    /// # Errors
+    ///
+    /// With the report when the policy rejects the failures
+    ///
+    pub fn run_tagged(
+        mut self,
+        title: &str,
+        description: &str,
+        tags: &[&str],
+        callbacks: Vec<&dyn Fn(&mut Self) -> &mut Self>,
+    ) -> Result<Report, Report> {
+        self.execute(title, description, tags, callbacks)
+    }
+
+    ///
+    /// # Set when the run fails
+    ///
+    /// - `policy` The failure policy
+    ///
+    pub fn policy(&mut self, policy: Policy) -> &mut Self {
+        self.policy = policy;
+        self
+    }
+
+    ///
+    /// # Set when the run fails before the run
+    ///
+    /// - `policy` The failure policy
+    ///
+    #[must_use]
+    pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    ///
+    /// # Run the callbacks with a failure policy and return the report
+    ///
+    /// The full report is printed before returning.
+    ///
+    /// - `title` The title
+    /// - `description` The description
+    /// - `sleep_time` The sleep time
+    /// - `policy` The failure policy
+    /// - `callbacks` The callbacks to execute
+    ///
+    /// # Errors
+    ///
+    /// With the report when the policy rejects the failures
+    ///
+    pub fn try_it(
+        title: &str,
+        description: &str,
+        sleep_time: u64,
+        policy: Policy,
+        callbacks: Vec<&dyn Fn(&mut Self) -> &mut Self>,
+    ) -> Result<Report, Report> {
+        Self::new(sleep_time)
+            .with_policy(policy)
+            .run_it(title, description, callbacks)
+    }
+
+    ///
+    /// # Run independent callbacks on a pool of workers
+    ///
+    /// Each callback runs with its own unit on one of the `UNIT_TESTING_WORKERS` threads,
+    /// the assertions are merged in the callbacks order.
+    /// The run never fails, `with_policy` then `run_parallel` apply a failure policy.
+    ///
+    /// - `title` The title
+    /// - `description` The description
+    /// - `sleep_time` The sleep time
+    /// - `callbacks` The callbacks to execute
+    ///
+    pub fn it_parallel(
+        title: &str,
+        description: &str,
+        sleep_time: u64,
+        callbacks: Vec<&(dyn Fn(&mut Self) -> &mut Self + Sync)>,
+    ) {
+        let _ = Self::new(sleep_time).run_parallel(title, description, callbacks);
+    }
+
+    ///
+    /// # Run callbacks on the workers and merge their assertions
+    ///
+    /// - `callbacks` The callbacks to execute
+    ///
+    fn parallel(&mut self, callbacks: &[&(dyn Fn(&mut Self) -> &mut Self + Sync)]) -> &mut Self {
+        let title: String = self.report.title.clone();
+        let (timeout, todo): (Option<Duration>, bool) = (self.timeout, self.todo);
+        let results = parallel::run(
+            callbacks.to_vec(),
+            &|callback: &(dyn Fn(&mut Self) -> &mut Self + Sync)| {
+                let mut worker: Self = Self::new(DISABLE_PROGRESS_TIME);
+                worker.reporters = Vec::new();
+                worker.report.title.clone_from(&title);
+                worker.timeout = timeout;
+                worker.todo = todo;
+                callback(&mut worker);
+                worker.report.records
+            },
+        );
+        for result in results {
+            let records: Vec<Record> = result.unwrap_or_else(|_| {
+                vec![Record::new(
+                    "parallel",
+                    ASSERT_PANIC,
+                    Outcome::Failed,
+                    0,
+                    &title,
+                )]
+            });
+            for record in records {
+                if record.passed() {
+                    self.s.set(self.s.get() + 1);
+                } else if record.failed() {
+                    self.f.set(self.f.get() + 1);
+                }
+                for reporter in &mut self.reporters {
+                    reporter.assertion(&record, 0);
+                }
+                self.report.records.push(record);
+            }
+        }
+        self
+    }
+
+    ///
+    /// # Run tagged callbacks
+    ///
+    /// The tags are selected or excluded by the `@tag` and `!@tag` filter terms.
+    /// The run never fails, `with_policy` then `run_tagged` apply a failure policy.
+    ///
+    /// - `title` The title
+    /// - `description` The description
+    /// - `sleep_time` The sleep time
+    /// - `tags` The tags
+    /// - `callbacks` The callbacks to execute
+    ///
+    pub fn it_tagged(
+        title: &str,
+        description: &str,
+        sleep_time: u64,
+        tags: &[&str],
+        callbacks: Vec<&dyn Fn(&mut Self) -> &mut Self>,
+    ) {
+        let _ = Self::new(sleep_time).run_tagged(title, description, tags, callbacks);
+    }
+
+    ///
+    /// # Skip the callbacks
+    ///
+    /// - `title` The title
+    /// - `description` The description
+    /// - `sleep_time` The sleep time
+    /// - `_callbacks` The skipped callbacks
+    ///
+    pub fn it_skip(
+        title: &str,
+        description: &str,
+        sleep_time: u64,
+        _callbacks: Vec<&dyn Fn(&mut Self) -> &mut Self>,
+    ) {
+        let skip: &dyn Fn(&mut Self) -> &mut Self = &|u: &mut Self| u.skip("it", title);
+        let _ = Self::new(sleep_time).execute(title, description, &[], vec![skip]);
+    }
+
+    ///
+    /// # Run unfinished callbacks
+    ///
+    /// The failures are recorded as todo and don't fail the run.
+    ///
+    /// - `title` The title
+    /// - `description` The description
+    /// - `sleep_time` The sleep time
+    /// - `callbacks` The callbacks to execute
+    ///
+    pub fn it_todo(
+        title: &str,
+        description: &str,
+        sleep_time: u64,
+        callbacks: Vec<&dyn Fn(&mut Self) -> &mut Self>,
+    ) {
+        let mut x = Self::new(sleep_time);
+        x.todo = true;
+        let _ = x.execute(title, description, &[], callbacks);
+    }
+
+    ///
+    /// # Print the header, run the callbacks and end the run
+    ///
+    /// - `title` The title
+    /// - `description` The description
+    /// - `tags` The tags
+    /// - `callbacks` The callbacks to execute
+    ///
+    fn execute(
+        &mut self,
+        title: &str,
+        description: &str,
+        tags: &[&str],
+        callbacks: Vec<&dyn Fn(&mut Self) -> &mut Self>,
+    ) -> Result<Report, Report> {
+        self.report = Report::new(title, description);
+        for reporter in &mut self.reporters {
+            reporter.suite_started(title, description);
+        }
+
+        let mut j = self;
+        if Filter::from_env().selects_tagged(title, tags) {
+            for &c in &callbacks {
+                j = c(j);
+            }
+        } else {
+            j = j.skip("it", title);
+        }
+
+        if j.end() {
+            Ok(j.report())
+        } else {
+            Err(j.report())
+        }
+    }
+
+    ///
+    /// # Fail the async assertions not completed before a timeout
+    ///
+    /// - `timeout` The maximum duration of a future
+    ///
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    ///
+    /// # Attach the expected and the actual values to the next assertion
+    ///
+    /// - `expected` The expected value
+    /// - `actual` The actual value
+    ///
+    fn values(&mut self, expected: &str, actual: &str) -> &mut Self {
+        self.values = Some((expected.to_string(), actual.to_string()));
+        self
+    }
+
+    ///
+    /// # Run a callback, the next record is timed from its start
+    ///
+    /// - `callback` The tested callback
+    ///
+    fn timed<T>(&mut self, callback: impl FnOnce() -> T) -> T {
+        self.clock = Some(Instant::now());
+        callback()
+    }
+
+    ///
+    /// # Run an assertion, measure the time and record it
+    ///
+    /// - `kind` The assertion kind
+    /// - `t` The test
+    /// - `s` The success output message
+    /// - `e` The error output message
+    ///
+    #[track_caller]
+    fn take_as(&mut self, kind: &str, t: bool, s: &str, e: &str) -> &mut Self {
+        self.check_as(kind, t, s, e);
+        self
+    }
+
+    ///
+    /// # Run an assertion without chaining, measure the time and record it
+    ///
+    /// - `kind` The assertion kind
+    /// - `t` The test
+    /// - `s` The success output message
+    /// - `e` The error output message
+    ///
+    #[track_caller]
+    fn check_as(&mut self, kind: &str, t: bool, s: &str, e: &str) {
+        let i: Instant = self.clock.take().unwrap_or_else(Instant::now);
+        if self.todo && !t {
+            self.record(kind, e, Outcome::Todo, i.elapsed().as_nanos());
+        } else if self.assert_that(t) {
+            self.record(kind, s, Outcome::Passed, i.elapsed().as_nanos());
+        } else {
+            self.record(kind, e, Outcome::Failed, i.elapsed().as_nanos());
+        }
+    }
+
+    ///
+    /// # Run a future, compare its output and record it
+    ///
+    /// - `kind` The assertion kind
+    /// - `future` The future to run
+    /// - `expected` The expected output
+    /// - `s` The success output message
+    /// - `e` The error output message
+    ///
+    #[track_caller]
+    fn take_async<T: PartialEq, F: Future<Output = T>>(
+        &mut self,
+        kind: &str,
+        future: F,
+        expected: &T,
+        s: &str,
+        e: &str,
+    ) -> &mut Self {
+        let timeout: Option<Duration> = self.timeout;
+        match self.timed(|| executor::block_on_timeout(future, timeout)) {
+            Some(actual) => self.take_as(kind, actual.eq(expected), s, e),
+            None => {
+                let e: String = executor::timed_out(self.timeout.unwrap_or_default());
+                self.take_as(kind, false, s, &e)
+            }
+        }
+    }
+
+    ///
+    /// # Append a skipped assertion to the report
+    ///
+    /// - `kind` The assertion kind
+    /// - `message` The output message
+    ///
+    #[track_caller]
+    fn skip(&mut self, kind: &str, message: &str) -> &mut Self {
+        self.record(kind, message, Outcome::Skipped, 0);
+        self
+    }
+
+    ///
+    /// # Append an assertion to the report
+    ///
+    /// - `kind` The assertion kind
+    /// - `message` The output message
+    /// - `outcome` The assertion outcome
+    /// - `take` The elapsed time in nanoseconds
+    ///
+    #[track_caller]
+    fn record(&mut self, kind: &str, message: &str, outcome: Outcome, take: u128) {
+        let mut record = Record::new(kind, message, outcome, take, &self.report.title);
+        if let Some((expected, actual)) = self.values.take() {
+            record = record.with_values(&expected, &actual);
+        }
+        record.location = Some(Location::caller());
+        for reporter in &mut self.reporters {
+            reporter.assertion(&record, 0);
+        }
+        self.report.records.push(record);
+    }
 }
 
 impl Success for Unit {
+    #[track_caller]
     fn run(&mut self, callbacks: Vec<&dyn Fn() -> Result<ExitStatus, io::Error>>) -> &mut Self {
         for &c in &callbacks {
-            self.check(c().unwrap().success(), IS_SUCCESS, IS_NOT_SUCCESS);
+            match self.timed(c) {
+                Ok(status) => {
+                    self.check_as("run", status.success(), IS_SUCCESS, IS_NOT_SUCCESS);
+                }
+                Err(e) => {
+                    self.check_as("run", false, IS_SUCCESS, &format!("{IS_SPAWN_FAILED}: {e}"));
+                }
+            }
         }
         self
     }
 
+    #[track_caller]
     fn success(&mut self, callbacks: Vec<&dyn Fn() -> bool>) -> &mut Self {
         for &c in &callbacks {
-            self.check(c(), IS_SUCCESS, IS_FAIL);
+            let t: bool = self.timed(c);
+            self.check_as("success", t, IS_SUCCESS, IS_FAIL);
         }
         self
     }
 }
 
 impl Theory for Unit {
+    #[track_caller]
     fn chaos(&mut self, callback: &dyn Fn() -> bool) -> &mut Self {
-        self.theory(false, &callback)
+        let t: bool = !self.timed(callback);
+        self.take_as("chaos", t, THEORY_IS_TRUE, THEORY_IS_FALSE)
     }
 
+    #[track_caller]
     fn theory<T: PartialEq>(&mut self, expected: T, callback: &dyn Fn() -> T) -> &mut Self {
-        self.take(callback() == expected, THEORY_IS_TRUE, THEORY_IS_FALSE)
+        let actual: T = self.timed(callback);
+        self.take_as(
+            "theory",
+            actual == expected,
+            THEORY_IS_TRUE,
+            THEORY_IS_FALSE,
+        )
     }
 
+    #[track_caller]
     fn theorem<T: PartialEq>(&mut self, expected: T, actual: &dyn Fn() -> T) -> &mut Self {
-        self.take(expected.eq(&actual()), THEORY_IS_TRUE, THEORY_IS_FALSE)
+        let actual: T = self.timed(actual);
+        self.take_as(
+            "theorem",
+            expected.eq(&actual),
+            THEORY_IS_TRUE,
+            THEORY_IS_FALSE,
+        )
+    }
+
+    #[track_caller]
+    fn theory_async<T: PartialEq, F: Future<Output = T>>(
+        &mut self,
+        expected: T,
+        future: F,
+    ) -> &mut Self {
+        self.take_async(
+            "theory_async",
+            future,
+            &expected,
+            THEORY_IS_TRUE,
+            THEORY_IS_FALSE,
+        )
+    }
+
+    #[track_caller]
+    fn for_all<G: Generator>(
+        &mut self,
+        generator: G,
+        property: &dyn Fn(G::Value) -> bool,
+    ) -> &mut Self {
+        match self.timed(|| property::check(&Config::from_env(), &generator, property)) {
+            Ok(_) => self.take_as("for_all", true, THEORY_IS_TRUE, THEORY_IS_FALSE),
+            Err(counterexample) => {
+                let e: String = counterexample.message(THEORY_IS_FALSE);
+                self.take_as("for_all", false, THEORY_IS_TRUE, &e)
+            }
+        }
     }
 }
 
@@ -68,80 +603,69 @@ impl Take for Unit {
         self.assert(t)
     }
 
+    #[track_caller]
     fn take(&mut self, t: bool, s: &str, e: &str) -> &mut Self {
-        let i: Instant = Instant::now();
-        if self.assert_that(t) {
-            assert_eq!(self.success.insert(self.s.get(), s.to_string()), None);
-            assert_eq!(
-                self.success_take
-                    .insert(self.s.get(), i.elapsed().as_nanos()),
-                None
-            );
-        } else {
-            assert_eq!(self.failure.insert(self.f.get(), e.to_string()), None);
-            assert_eq!(
-                self.failure_take
-                    .insert(self.f.get(), i.elapsed().as_nanos()),
-                None
-            );
-        }
-
-        self
+        self.take_as("take", t, s, e)
     }
 
+    #[track_caller]
     fn check(&mut self, t: bool, s: &str, e: &str) {
-        let i: Instant = Instant::now();
-        if self.assert_that(t) {
-            assert!(self.success.insert(self.s.get(), s.to_string()).is_some());
-            assert!(self
-                .success_take
-                .insert(self.s.get(), i.elapsed().as_nanos())
-                .is_some());
-        } else {
-            assert!(self.failure.insert(self.f.get(), e.to_string()).is_some());
-            assert!(self
-                .failure_take
-                .insert(self.f.get(), i.elapsed().as_nanos())
-                .is_some());
-        }
+        self.check_as("check", t, s, e);
     }
 }
 
 impl Failure for Unit {
+    #[track_caller]
     fn command_fail(
         &mut self,
         callbacks: Vec<&dyn Fn() -> Result<ExitStatus, io::Error>>,
     ) -> &mut Self {
         for &c in &callbacks {
-            let status: ExitStatus = c().unwrap();
-            self.check(!status.success(), IS_FAIL, IS_NOT_FAIL);
+            match self.timed(c) {
+                Ok(status) => {
+                    self.check_as("command_fail", !status.success(), IS_FAIL, IS_NOT_FAIL);
+                }
+                Err(e) => {
+                    self.check_as(
+                        "command_fail",
+                        false,
+                        IS_FAIL,
+                        &format!("{IS_SPAWN_FAILED}: {e}"),
+                    );
+                }
+            }
         }
         self
     }
 
+    #[track_caller]
     fn fail(&mut self, callbacks: Vec<&dyn Fn() -> bool>) -> &mut Self {
         for &c in &callbacks {
-            self.check(!c(), IS_FAIL, IS_NOT_FAIL);
+            let t: bool = !self.timed(c);
+            self.check_as("fail", t, IS_FAIL, IS_NOT_FAIL);
         }
         self
     }
 }
 
 impl Testable for Unit {
+    #[track_caller]
     fn matches(&mut self, pattern: &str, values: Vec<String>) -> &mut Self {
         let r = Regex::new(pattern).unwrap();
 
         for x in &values {
-            self.check(r.is_match(x.as_str()), IS_MATCH, IS_NOT_MATCH);
+            self.check_as("matches", r.is_match(x.as_str()), IS_MATCH, IS_NOT_MATCH);
         }
         self
     }
 
+    #[track_caller]
     fn capture(&mut self, pattern: &str, x: &str, key: usize, values: Vec<String>) -> &mut Self {
         let r: Regex = Regex::new(pattern).unwrap();
         let caps = r.captures(x).unwrap();
         for v in &values {
-            self.check(
+            self.check_as(
+                "capture",
                 caps.get(key)
                     .expect("failed to get key")
                     .as_str()
@@ -159,27 +683,17 @@ impl Testable for Unit {
         sleep_time: u64,
         callbacks: Vec<&dyn Fn(&mut Self) -> &mut Self>,
     ) {
-        println!("\n{}\n", description.white().bold());
-        println!(
-            "      {}",
-            format_args!("{} {}", "[ OK ]".green().bold(), title.blue().bold())
-        );
-        let mut x = Self::new(sleep_time);
-
-        let mut j = &mut x;
-        for &c in &callbacks {
-            j = c(j);
-        }
-
-        let _ = j.end();
+        let _ = Self::new(sleep_time).execute(title, description, &[], callbacks);
     }
 
+    #[track_caller]
     fn ok(&mut self, f: bool) -> &mut Self {
-        self.take(f, IS_OK, IS_KO)
+        self.take_as("ok", f, IS_OK, IS_KO)
     }
 
+    #[track_caller]
     fn ko(&mut self, f: bool) -> &mut Self {
-        self.take(!f, IS_KO, IS_OK)
+        self.take_as("ko", !f, IS_KO, IS_OK)
     }
 
     fn assert(&mut self, test: bool) -> bool {
@@ -191,63 +705,193 @@ impl Testable for Unit {
         test
     }
 
-    fn eq<T: PartialEq>(&mut self, a: T, b: T) -> &mut Self {
-        self.take(a.eq(&b), IS_EQUALS, IS_UNEQUALS)
+    #[track_caller]
+    fn expect<T: ?Sized, M: Matcher<T>>(&mut self, actual: &T, matcher: M) -> &mut Self {
+        let (t, s, e): (bool, String, String) = matcher::check(actual, &matcher);
+        self.take_as("expect", t, &s, &e)
+    }
+
+    #[track_caller]
+    fn eq<T: PartialEq + Debug>(&mut self, a: T, b: T) -> &mut Self {
+        let (t, s, e): (bool, String, String) = matcher::check(&a, &matcher::eq(b));
+        self.take_as("eq", t, &s, &e)
+    }
+
+    #[track_caller]
+    fn eq_debug<T: PartialEq + Debug>(&mut self, a: T, b: T) -> &mut Self {
+        self.values(&format!("{b:#?}"), &format!("{a:#?}")).take_as(
+            "eq_debug",
+            a.eq(&b),
+            IS_EQUALS,
+            IS_UNEQUALS,
+        )
+    }
+
+    #[track_caller]
+    fn approx_eq<T: Approx + Debug + ?Sized>(
+        &mut self,
+        actual: &T,
+        expected: &T,
+        tolerance: Tolerance,
+    ) -> &mut Self {
+        let result: Result<(), String> = actual.approx(expected, tolerance);
+        let e: String = format!(
+            "{IS_NOT_APPROX_EQUALS}: {}",
+            result.as_ref().err().map_or("", String::as_str)
+        );
+        self.values(&format!("{expected:#?}"), &format!("{actual:#?}"))
+            .take_as("approx_eq", result.is_ok(), IS_APPROX_EQUALS, &e)
     }
 
+    #[track_caller]
+    fn eq_async<T: PartialEq, F: Future<Output = T>>(&mut self, a: F, b: T) -> &mut Self {
+        self.take_async("eq_async", a, &b, IS_EQUALS, IS_UNEQUALS)
+    }
+
+    #[track_caller]
+    fn within<F: FnOnce() -> bool + Send + 'static>(
+        &mut self,
+        timeout: Duration,
+        callback: F,
+    ) -> &mut Self {
+        match self.timed(|| executor::within(Some(timeout), callback)) {
+            Some(Ok(t)) => self.take_as("within", t, IS_OK, IS_KO),
+            Some(Err(_)) => self.take_as("within", false, IS_OK, ASSERT_PANIC),
+            None => self.take_as("within", false, IS_OK, &executor::timed_out(timeout)),
+        }
+    }
+
+    #[track_caller]
+    fn snapshot(&mut self, name: &str, value: &str) -> &mut Self {
+        match snapshot::check(Location::caller().file(), name, value)
+            .unwrap_or_else(|e| panic!("The snapshot {name} can't be checked: {e}"))
+        {
+            Snapshot::Changed(expected) => self.values(&expected, value).take_as(
+                "snapshot",
+                false,
+                IS_SNAPSHOT_MATCH,
+                IS_SNAPSHOT_CHANGED,
+            ),
+            Snapshot::New => self.take_as("snapshot", false, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_NEW),
+            Snapshot::Matched | Snapshot::Accepted => {
+                self.take_as("snapshot", true, IS_SNAPSHOT_MATCH, IS_SNAPSHOT_CHANGED)
+            }
+        }
+    }
+
+    #[track_caller]
+    fn command(&mut self, command: &Command) -> &mut Self {
+        let file: &str = Location::caller().file();
+        for check in self.timed(|| command.verify(file)) {
+            if let Some((expected, actual)) = &check.values {
+                self.values(expected, actual);
+            }
+            self.take_as(&check.kind, check.passed, &check.success, &check.error);
+        }
+        self
+    }
+
+    #[track_caller]
+    fn str_eq(&mut self, actual: &str, expected: &str) -> &mut Self {
+        self.values(expected, actual)
+            .take_as("str_eq", actual.eq(expected), IS_EQUALS, IS_UNEQUALS)
+    }
+
+    #[track_caller]
+    fn ne_debug<T: PartialEq + Debug>(&mut self, a: T, b: T) -> &mut Self {
+        self.values(&format!("{b:#?}"), &format!("{a:#?}")).take_as(
+            "ne_debug",
+            a.ne(&b),
+            IS_UNEQUALS,
+            IS_EQUALS,
+        )
+    }
+
+    #[track_caller]
     fn ne<T: PartialEq>(&mut self, a: T, b: T) -> &mut Self {
-        self.take(a.ne(&b), IS_UNEQUALS, IS_EQUALS)
+        self.take_as("ne", a.ne(&b), IS_UNEQUALS, IS_EQUALS)
     }
 
+    #[track_caller]
     fn gt<T: PartialOrd>(&mut self, a: T, min: T) -> &mut Self {
-        self.take(a.gt(&min), IS_SUPERIOR, IS_INFERIOR)
+        self.take_as("gt", a.gt(&min), IS_SUPERIOR, IS_INFERIOR)
     }
 
+    #[track_caller]
     fn ge<T: PartialOrd>(&mut self, a: T, max: T) -> &mut Self {
-        self.take(a.ge(&max), IS_INFERIOR, IS_SUPERIOR)
+        self.take_as("ge", a.ge(&max), IS_INFERIOR, IS_SUPERIOR)
     }
+    #[track_caller]
     fn lt<T: PartialOrd>(&mut self, a: T, max: T) -> &mut Self {
-        self.take(a.lt(&max), IS_SUPERIOR, IS_INFERIOR)
+        self.take_as("lt", a.lt(&max), IS_SUPERIOR, IS_INFERIOR)
     }
 
+    #[track_caller]
     fn le<T: PartialOrd>(&mut self, a: T, max: T) -> &mut Self {
-        self.take(a.le(&max), IS_INFERIOR, IS_SUPERIOR)
+        self.take_as("le", a.le(&max), IS_INFERIOR, IS_SUPERIOR)
     }
 
-    fn between<T: PartialOrd>(&mut self, a: T, min: T, max: T) -> &mut Self {
-        self.take(a > min && a < max, IS_BETWEEN, IS_NOT_BETWEEN)
+    #[track_caller]
+    fn between<T: PartialOrd + Debug>(&mut self, a: T, min: T, max: T) -> &mut Self {
+        let (t, s, e): (bool, String, String) = matcher::check(&a, &matcher::between(min, max));
+        self.take_as("between", t, &s, &e)
     }
 
+    #[track_caller]
     fn vec_contains<T: PartialEq>(&mut self, a: Vec<T>, b: T) -> &mut Self {
-        self.take(a.contains(&b), IS_CONTAINS, IS_NOT_CONTAINS)
+        self.take_as("vec_contains", a.contains(&b), IS_CONTAINS, IS_NOT_CONTAINS)
     }
 
+    #[track_caller]
     fn exe(&mut self, p: &str) -> &mut Self {
-        self.take(
+        self.take_as(
+            "exe",
             Path::new(p).is_executable(),
             IS_EXECUTABLE,
             IS_NOT_EXECUTABLE,
         )
     }
 
+    #[track_caller]
     fn vec_no_contains<T: PartialEq>(&mut self, a: Vec<T>, b: T) -> &mut Self {
-        self.take(!a.contains(&b), IS_CONTAINS, IS_NOT_CONTAINS)
+        self.take_as(
+            "vec_no_contains",
+            !a.contains(&b),
+            IS_CONTAINS,
+            IS_NOT_CONTAINS,
+        )
     }
 
+    #[track_caller]
     fn option_contains<T: PartialEq>(&mut self, a: Option<T>, b: T) -> &mut Self {
-        self.take(a.expect("failed") == b, IS_CONTAINS, IS_NOT_CONTAINS)
+        self.take_as(
+            "option_contains",
+            a.expect("failed") == b,
+            IS_CONTAINS,
+            IS_NOT_CONTAINS,
+        )
     }
 
+    #[track_caller]
     fn hash_contains(&mut self, a: &mut HashSet<String>, b: String) -> &mut Self {
-        self.take(a.contains(&b), IS_CONTAINS, IS_NOT_CONTAINS)
+        self.take_as(
+            "hash_contains",
+            a.contains(&b),
+            IS_CONTAINS,
+            IS_NOT_CONTAINS,
+        )
     }
 
+    #[track_caller]
     fn str_contains(&mut self, a: &str, b: &str) -> &mut Self {
-        self.take(a.contains(b), IS_CONTAINS, IS_NOT_CONTAINS)
+        let (t, s, e): (bool, String, String) = matcher::check(a, &matcher::str_contains(b));
+        self.values(b, a).take_as("str_contains", t, &s, &e)
     }
 
+    #[track_caller]
     fn file_contains(&mut self, f: &str, v: &str) -> &mut Self {
-        self.take(
+        self.take_as(
+            "file_contains",
             fs::read_to_string(f)
                 .unwrap_or_else(|_| panic!("The filename {f} has not been founded"))
                 .contains(v),
@@ -256,106 +900,83 @@ impl Testable for Unit {
         )
     }
 
+    #[track_caller]
     fn exists(&mut self, p: &str) -> &mut Self {
-        self.take(Path::new(p).exists(), IS_EXISTS, IS_NOT_EXISTS)
+        self.take_as("exists", Path::new(p).exists(), IS_EXISTS, IS_NOT_EXISTS)
     }
 
+    #[track_caller]
     fn not_exists(&mut self, p: &str) -> &mut Self {
-        self.take(!Path::new(p).exists(), IS_NOT_EXISTS, IS_EXISTS)
+        self.take_as(
+            "not_exists",
+            !Path::new(p).exists(),
+            IS_NOT_EXISTS,
+            IS_EXISTS,
+        )
     }
 
+    #[track_caller]
     fn start_with(&mut self, actual: &str, expected: &str) -> &mut Self {
-        self.take(actual.starts_with(expected), IS_BEGIN, IS_NOT_BEGIN)
+        self.values(expected, actual).take_as(
+            "start_with",
+            actual.starts_with(expected),
+            IS_BEGIN,
+            IS_NOT_BEGIN,
+        )
     }
 
+    #[track_caller]
     fn end_with(&mut self, actual: &str, expected: &str) -> &mut Self {
-        self.take(actual.ends_with(expected), IS_FINNISH, IS_NOT_FINNISH)
+        self.values(expected, actual).take_as(
+            "end_with",
+            actual.ends_with(expected),
+            IS_FINNISH,
+            IS_NOT_FINNISH,
+        )
     }
 
     fn end(&mut self) -> bool {
-        let total: usize = self.f.get() + self.s.get();
-        init_progress_bar_with_eta(total);
-        set_progress_bar_action("[ :: ]", Color::Green, Style::Bold);
-
-        let mut failure = self.failure.values();
-        let mut success = self.success.values();
-        let mut success_take = self.success_take.values();
-        let mut failures_take = self.failure_take.values();
-
-        for _i in 0..total {
-            sleep(Duration::from_millis(self.sleep));
-
-            if let Some(x) = success.next() {
-                print_progress_bar_info(
-                    "[ OK ]",
-                    format!(
-                        "{} {} {} {}",
-                        x.blue().bold(),
-                        "take".white().bold(),
-                        success_take.next().expect("").to_string().cyan().bold(),
-                        "ns".blue().bold()
-                    )
-                    .as_str(),
-                    Color::Green,
-                    Style::Bold,
-                );
-            }
-
-            if let Some(x) = failure.next() {
-                print_progress_bar_info(
-                    "[ KO ]",
-                    format!(
-                        "{} {} {} {}",
-                        x.purple().bold(),
-                        "take".white().bold(),
-                        failures_take.next().expect("").to_string().cyan().bold(),
-                        "ns".blue().bold()
-                    )
-                    .as_str(),
-                    Color::Red,
-                    Style::Bold,
-                );
-            }
-            inc_progress_bar();
+        if let Err(e) = reporter::finish(&mut self.reporters, &self.report) {
+            let e: String = format!("{IS_REPORT_NOT_WRITTEN}: {e}");
+            self.check_as("report", false, IS_REPORT_WRITTEN, &e);
         }
+        self.policy.accept(self.f.get())
+    }
 
-        print_progress_bar_final_info(
-            "[ OK ]",
-            format!(
-                "{} {} {} {}",
-                "Assertions :".blue().bold(),
-                self.s.get().to_string().green().bold(),
-                "Failures :".blue().bold(),
-                self.f.get().to_string().red().bold(),
-            )
-            .as_str(),
-            Color::Green,
-            Style::Bold,
-        );
-        finalize_progress_bar();
-        true
+    fn report(&self) -> Report {
+        self.report.clone()
     }
 
     fn new(sleep_time: u64) -> Self {
         Self {
             s: Cell::new(0),
             f: Cell::new(0),
-            sleep: sleep_time,
-            success_take: HashMap::new(),
-            failure_take: HashMap::new(),
-            success: HashMap::new(),
-            failure: HashMap::new(),
+            report: Report::default(),
+            reporters: reporter::progress(sleep_time, false),
+            values: None,
+            clock: None,
+            timeout: None,
+            policy: Policy::default(),
+            todo: false,
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::approx::Tolerance;
     use crate::check_that;
-    use crate::objects::{Testable, Theory};
+    use crate::matcher;
+    use crate::objects::{Success, Testable, Theory};
     use crate::output::DISABLE_PROGRESS_TIME;
-    use crate::unit::Unit;
+    use crate::output::IS_REPORT_NOT_WRITTEN;
+    use crate::property::{self, any, between};
+    use crate::report::Record;
+    use crate::reporter::Recorder;
+    use crate::unit::{Policy, Unit};
     use std::collections::HashSet;
+    use std::thread::sleep;
+    use std::time::Duration;
 
     fn ok() -> bool {
         true
@@ -437,6 +1058,295 @@ mod test {
         u.theory(5.0, &pythagore).chaos(&pythagore_not_work)
     }
 
+    #[test]
+    pub fn report() {
+        let report = Unit::new(DISABLE_PROGRESS_TIME)
+            .eq(1, 1)
+            .ok(false)
+            .str_contains("linux", "nux")
+            .str_eq("linux", "linus")
+            .end_with_report();
+        assert_eq!(report.total(), 4);
+        assert_eq!(report.assertions(), 2);
+        assert_eq!(report.failures(), 2);
+        assert_eq!(report.records[1].kind, "ok");
+        assert_eq!(report.records[3].expected.as_deref(), Some("linus"));
+        assert_eq!(report.records[3].actual.as_deref(), Some("linux"));
+        assert_eq!(report.records[3].location.map(|l| l.file()), Some(file!()));
+        assert!(!report.is_success());
+    }
+
+    #[test]
+    pub fn approx() {
+        let report = Unit::new(DISABLE_PROGRESS_TIME)
+            .approx_eq(&(0.1 + 0.2), &0.3, Tolerance::Ulps(1))
+            .approx_eq(&[3.0_f32.hypot(4.0)], &[5.0], Tolerance::Relative(1e-6))
+            .approx_eq(&f64::INFINITY, &f64::INFINITY, Tolerance::Absolute(0.1))
+            .approx_eq(&1.0, &1.5, Tolerance::Absolute(0.1))
+            .end_with_report();
+        let passed: Vec<bool> = report.records.iter().map(Record::passed).collect();
+        assert_eq!(passed, vec![true, true, true, false]);
+        assert!(report.records[3]
+            .message
+            .ends_with("1 and 1.5 differ by 5e-1, the tolerance is absolute 1e-1"));
+    }
+
+    #[test]
+    pub fn matchers() {
+        let report = Unit::new(DISABLE_PROGRESS_TIME)
+            .expect(
+                &50,
+                matcher::all_of(vec![Box::new(matcher::gt(10)), Box::new(matcher::le(200))]),
+            )
+            .expect("a-b", matcher::not(matcher::matches("^[0-9]+$")))
+            .expect(&6, matcher::between(5, 9))
+            .expect(&5, matcher::between(5, 9))
+            .between(5, 5, 9)
+            .expect("uuid", matcher::str_contains("-"))
+            .str_contains("uuid", "-")
+            .expect(&1, matcher::eq(2))
+            .eq(1, 2)
+            .end_with_report();
+        let passed: Vec<bool> = report.records.iter().map(Record::passed).collect();
+        assert_eq!(
+            passed,
+            vec![true, true, true, false, false, false, false, false, false]
+        );
+        assert_eq!(report.records[5].message, "containing \"-\", was \"uuid\"");
+        for (expect, builtin) in [(3, 4), (5, 6), (7, 8)] {
+            assert_eq!(
+                report.records[expect].message,
+                report.records[builtin].message
+            );
+        }
+    }
+
+    #[test]
+    pub fn properties() {
+        let report = Unit::new(DISABLE_PROGRESS_TIME)
+            .for_all(any::<(f32, f32)>(), &|(a, b)| a.hypot(b) >= a.abs())
+            .for_all(between(0_u32, 1000), &|x| x < 100)
+            .end_with_report();
+        let passed: Vec<bool> = report.records.iter().map(Record::passed).collect();
+        assert_eq!(passed, vec![true, false]);
+        assert!(report.records[1].message.contains(property::SEED));
+    }
+
+    #[test]
+    pub fn reporters() {
+        let recorder = Recorder::default();
+        let report = Unit::new(DISABLE_PROGRESS_TIME)
+            .with_reporter(Box::new(recorder.clone()))
+            .run_it(
+                "Test the custom reporters",
+                "A reporter added before the run receives all the events",
+                vec![&must_pass, &|u: &mut Unit| u.ok(false)],
+            )
+            .expect("the default policy never fails");
+        assert_eq!(report.failures(), 1);
+        assert_eq!(
+            recorder.events(),
+            vec!["Test the custom reporters", "ok 0", "ko 0", "ok 0"]
+        );
+    }
+
+    #[test]
+    pub fn unwritten() {
+        let mut unit = Unit::new(DISABLE_PROGRESS_TIME);
+        unit.reporters
+            .push(Box::new(Recorder::default().failing("disk full")));
+        unit.policy(Policy::Any).ok(true);
+        assert!(!unit.end());
+        let report = unit.report();
+        assert_eq!(report.failures(), 1);
+        assert_eq!(
+            report.records[1].message,
+            format!("{IS_REPORT_NOT_WRITTEN}: disk full")
+        );
+    }
+
+    #[test]
+    pub fn take() {
+        let report = Unit::new(DISABLE_PROGRESS_TIME)
+            .success(vec![&|| {
+                sleep(Duration::from_millis(20));
+                true
+            }])
+            .eq(1, 1)
+            .end_with_report();
+        assert!(report.records[0].take >= 20_000_000);
+        assert!(report.records[1].take < 20_000_000);
+    }
+
+    #[test]
+    pub fn values() {
+        let report = Unit::new(DISABLE_PROGRESS_TIME)
+            .eq_debug(vec![1, 2], vec![1, 2])
+            .ne_debug(Some(1), None)
+            .str_eq("linux", "linux")
+            .eq_debug(vec![1], vec![2])
+            .end_with_report();
+        let passed: Vec<bool> = report.records.iter().map(Record::passed).collect();
+        assert_eq!(passed, vec![true, true, true, false]);
+        assert_eq!(report.records[3].expected.as_deref(), Some("[\n    2,\n]"));
+        assert_eq!(report.records[3].actual.as_deref(), Some("[\n    1,\n]"));
+        assert!(report.records[3].diff().is_some());
+    }
+
+    #[test]
+    pub fn futures() {
+        let report = Unit::new(DISABLE_PROGRESS_TIME)
+            .timeout(Duration::from_millis(50))
+            .eq_async(async { 4 }, 4)
+            .theory_async(5.0, async { pythagore() })
+            .eq_async(async { 3 }, 4)
+            .eq_async(std::future::pending::<i32>(), 4)
+            .end_with_report();
+        let passed: Vec<bool> = report.records.iter().map(Record::passed).collect();
+        assert_eq!(passed, vec![true, true, false, false]);
+    }
+
+    #[test]
+    pub fn ordered() {
+        let report = Unit::new(DISABLE_PROGRESS_TIME)
+            .ok(true)
+            .ko(true)
+            .eq(1, 1)
+            .success(vec![&ok, &ko])
+            .within(Duration::from_millis(10), || {
+                sleep(Duration::from_secs(1));
+                true
+            })
+            .eq(2, 2)
+            .end_with_report();
+        let kinds: Vec<(&str, bool)> = report
+            .records
+            .iter()
+            .map(|r| (r.kind.as_str(), r.passed()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("ok", true),
+                ("ko", false),
+                ("eq", true),
+                ("success", true),
+                ("success", false),
+                ("within", false),
+                ("eq", true),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn policy() {
+        assert!(check_that!(
+            "Test the failure policy",
+            "A failure is accepted under the threshold",
+            DISABLE_PROGRESS_TIME,
+            Policy::Threshold(1),
+            vec![&must_pass, &|u: &mut Unit| u.ok(false)]
+        )
+        .is_ok());
+        let report = Unit::try_it(
+            "Test the failure policy",
+            "Any failure is rejected",
+            DISABLE_PROGRESS_TIME,
+            Policy::Any,
+            vec![&must_pass, &|u: &mut Unit| u.ok(false)],
+        )
+        .expect_err("the run must fail");
+        assert_eq!(report.failures(), 1);
+        let parallel = Unit::new(DISABLE_PROGRESS_TIME)
+            .with_policy(Policy::Any)
+            .run_parallel(
+                "Test the failure policy",
+                "The parallel failures are rejected",
+                vec![&must_pass, &|u: &mut Unit| u.ok(false)],
+            )
+            .expect_err("the parallel run must fail");
+        assert_eq!(parallel.failures(), 1);
+        assert!(Unit::new(DISABLE_PROGRESS_TIME)
+            .with_policy(Policy::Any)
+            .run_tagged(
+                "Test the failure policy",
+                "The tagged failures are rejected",
+                &["fast"],
+                vec![&|u: &mut Unit| u.ok(false)],
+            )
+            .is_err());
+        assert!(!Unit::new(DISABLE_PROGRESS_TIME)
+            .policy(Policy::Any)
+            .ko(true)
+            .end());
+    }
+
+    #[test]
+    pub fn markers() {
+        Unit::it_tagged(
+            "Test the tags",
+            "A tagged run is selected without filter",
+            DISABLE_PROGRESS_TIME,
+            &["fast"],
+            vec![&must_pass],
+        );
+        Unit::it_skip(
+            "Test the skip marker",
+            "The callbacks are not run",
+            DISABLE_PROGRESS_TIME,
+            vec![&|u: &mut Unit| u.ok(false)],
+        );
+        Unit::it_todo(
+            "Test the todo marker",
+            "The failures are recorded as todo",
+            DISABLE_PROGRESS_TIME,
+            vec![&|u: &mut Unit| u.ok(false)],
+        );
+        let mut unit = Unit::new(DISABLE_PROGRESS_TIME);
+        unit.todo = true;
+        let report = unit.ok(true).ok(false).end_with_report();
+        assert_eq!(
+            (report.assertions(), report.todo(), report.failures()),
+            (1, 1, 0)
+        );
+    }
+
+    #[test]
+    pub fn parallel() {
+        Unit::it_parallel(
+            "Test the parallel runner",
+            "The callbacks run on the workers",
+            DISABLE_PROGRESS_TIME,
+            vec![&must_pass, &must_equals, &must_between],
+        );
+        let report = Unit::new(DISABLE_PROGRESS_TIME)
+            .parallel(&[
+                &|u: &mut Unit| {
+                    sleep(Duration::from_millis(50));
+                    u.eq(1, 1)
+                },
+                &|u: &mut Unit| u.ok(false),
+                &|_: &mut Unit| panic!("the worker panicked"),
+                &|u: &mut Unit| u.ko(false),
+            ])
+            .end_with_report();
+        let kinds: Vec<(&str, bool)> = report
+            .records
+            .iter()
+            .map(|r| (r.kind.as_str(), r.passed()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("eq", true),
+                ("ok", false),
+                ("parallel", false),
+                ("ko", true)
+            ]
+        );
+        assert_eq!((report.assertions(), report.failures()), (2, 2));
+    }
+
     #[test]
     pub fn all() {
         check_that!(